@@ -0,0 +1,49 @@
+use utils::solution::Variant;
+
+/// Variants of a day crate, see the `variants` function of each day.
+pub type VariantsFn = fn() -> Vec<Variant>;
+
+/// Every day crate `aoc` can run as year, day and its variants, sorted by year and day.
+/// `aoc scaffold` adds the days it creates here and to the dependencies in `Cargo.toml`.
+pub const DAYS: &[(u16, u8, VariantsFn)] = &[
+    (2024, 1, d01::variants),
+    (2024, 2, d02::variants),
+    (2024, 3, d03::variants),
+    (2024, 4, d04::variants),
+    (2024, 5, d05::variants),
+    (2024, 6, d06::variants),
+    (2024, 7, d07::variants),
+    (2024, 8, d08::variants),
+    (2024, 9, d09::variants),
+    (2024, 10, d10::variants),
+    (2024, 11, d11::variants),
+    (2024, 12, d12::variants),
+    (2024, 13, d13::variants),
+    (2024, 14, d14::variants),
+    (2024, 15, d15::variants),
+    (2024, 16, d16::variants),
+    (2024, 17, d17::variants),
+    (2024, 18, d18::variants),
+    (2024, 19, d19::variants),
+    (2024, 20, d20::variants),
+    (2024, 21, d21::variants),
+    (2024, 22, d22::variants),
+    (2024, 23, d23::variants),
+    (2024, 24, d24::variants),
+    (2024, 25, d25::variants),
+];
+
+/// The variants of `day` in `year`, `None` if there is no crate for it.
+pub fn find_day(year: u16, day: u8) -> Option<VariantsFn> {
+    DAYS.iter()
+        .find(|&&(y, d, _)| y == year && d == day)
+        .map(|&(_, _, variants)| variants)
+}
+
+/// All days of `year` that have a crate.
+pub fn days_of(year: u16) -> Vec<u8> {
+    DAYS.iter()
+        .filter(|&&(y, _, _)| y == year)
+        .map(|&(_, day, _)| day)
+        .collect()
+}
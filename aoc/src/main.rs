@@ -0,0 +1,371 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use utils::answers::get_parameters;
+use utils::error::AocError;
+use utils::inputs::{InputResolver, DEFAULT_YEAR};
+use utils::solution::{DayFn, Part};
+
+use crate::days::{days_of, find_day};
+use crate::report::{measure, render, DaySummary, Format};
+use crate::scaffold::crate_path;
+
+mod days;
+mod remote;
+mod report;
+mod scaffold;
+
+const USAGE: &str = "Usage: aoc <day|all> [--year <year>] [--part <1|2>] [--input <file> | --example <name>] [--param <name>=<value>]... [--variant <name>] [--repeat <n>] [--format <table|json|csv>]
+       aoc fetch <day|all> [--year <year>]
+       aoc submit <day> <1|2> <answer> [--year <year>]
+       aoc scaffold <day> [--year <year>] [--template <lines|single-line|numbers>] [--fetch]";
+
+#[derive(Debug)]
+struct Args {
+    year: u16,
+    days: Vec<usize>,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    example: Option<String>,
+    params: Vec<(String, String)>,
+    variant: Option<String>,
+    repeat: usize,
+    format: Format,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            year: DEFAULT_YEAR,
+            days: Vec::new(),
+            part: None,
+            input: None,
+            example: None,
+            params: Vec::new(),
+            variant: None,
+            repeat: 1,
+            format: Format::default(),
+        }
+    }
+}
+
+/// Parses a day number or `all`, which are the days of `year` that have a crate.
+fn parse_days(arg: &str, year: u16) -> Result<Vec<usize>, String> {
+    if arg == "all" {
+        let days: Vec<usize> = days_of(year).into_iter().map(usize::from).collect();
+        if days.is_empty() {
+            return Err(format!("No days of {year}, create them with aoc scaffold"));
+        }
+        return Ok(days);
+    }
+
+    match arg.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(vec![day]),
+        _ => Err(format!("Invalid day '{arg}', expected 1 to 25 or all")),
+    }
+}
+
+fn parse_year(value: Option<String>) -> Result<u16, String> {
+    let value = value.ok_or("Missing value for --year")?;
+    value.parse().map_err(|_| format!("Invalid year '{value}'"))
+}
+
+fn parse_part(arg: &str) -> Result<Part, String> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part '{arg}', expected 1 or 2")),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut day_arg = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => parsed.year = parse_year(args.next())?,
+            "--part" | "-p" => {
+                let value = args.next().ok_or("Missing value for --part")?;
+                parsed.part = Some(parse_part(&value)?);
+            }
+            "--input" | "-i" => {
+                let value = args.next().ok_or("Missing value for --input")?;
+                parsed.input = Some(PathBuf::from(value));
+            }
+            "--example" | "-e" => {
+                let value = args.next().ok_or("Missing value for --example")?;
+                parsed.example = Some(value);
+            }
+            "--param" => {
+                let value = args.next().ok_or("Missing value for --param")?;
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid param '{value}', expected <name>=<value>"))?;
+                parsed.params.push((name.to_string(), value.to_string()));
+            }
+            "--variant" | "-v" => {
+                let value = args.next().ok_or("Missing value for --variant")?;
+                parsed.variant = Some(value);
+            }
+            "--repeat" | "-r" => {
+                let value = args.next().ok_or("Missing value for --repeat")?;
+                parsed.repeat = match value.parse() {
+                    Ok(repeat) if repeat > 0 => repeat,
+                    _ => {
+                        return Err(format!(
+                            "Invalid repeat '{value}', expected a positive number"
+                        ))
+                    }
+                };
+            }
+            "--format" | "-f" => {
+                let value = args.next().ok_or("Missing value for --format")?;
+                parsed.format = value.parse()?;
+            }
+            _ if day_arg.is_some() => return Err(format!("Unexpected argument '{arg}'")),
+            _ => day_arg = Some(arg),
+        }
+    }
+
+    // `all` depends on the year, which may come after the day.
+    let day_arg = day_arg.ok_or("No day selected")?;
+    parsed.days = parse_days(&day_arg, parsed.year)?;
+
+    if parsed.input.is_some() && parsed.days.len() > 1 {
+        return Err(String::from("--input can only be used with a single day"));
+    }
+
+    if parsed.input.is_some() && parsed.example.is_some() {
+        return Err(String::from(
+            "--input and --example cannot be used together",
+        ));
+    }
+
+    if parsed.variant.is_some() && parsed.days.len() > 1 {
+        return Err(String::from("--variant can only be used with a single day"));
+    }
+
+    Ok(parsed)
+}
+
+/// Resolver of `day` that looks in the same places as the day crate itself.
+fn day_resolver(year: u16, day: usize) -> InputResolver {
+    let day_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(crate_path(year, day as u8));
+    InputResolver::for_crate(day_dir)
+}
+
+/// Finds the input, or the example called `example`, of `day` in `year`.
+fn resolve_input(year: u16, day: usize, example: Option<&str>) -> Result<PathBuf, AocError> {
+    let resolver = day_resolver(year, day);
+
+    match example {
+        None => resolver.input(),
+        Some(name) => resolver.example(name),
+    }
+}
+
+/// Picks the requested variant of a day, or its first one which is the main solution.
+fn select_variant(year: u16, day: usize, name: Option<&str>) -> Result<DayFn, String> {
+    let variants_fn = find_day(year, day as u8)
+        .ok_or_else(|| format!("No crate for day {day} of {year}, create it with aoc scaffold"))?;
+    let variants = variants_fn();
+    let Some(name) = name else {
+        return Ok(variants[0].1);
+    };
+
+    variants
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, day_fn)| *day_fn)
+        .ok_or_else(|| {
+            let names: Vec<&str> = variants.iter().map(|(n, _)| *n).collect();
+            format!(
+                "Unknown variant '{name}', expected one of {}",
+                names.join(", ")
+            )
+        })
+}
+
+fn run_day(day: usize, args: &Args) -> Result<DaySummary, String> {
+    let day_fn = select_variant(args.year, day, args.variant.as_deref())?;
+    let input_file = match &args.input {
+        Some(input_file) => input_file.clone(),
+        None => {
+            resolve_input(args.year, day, args.example.as_deref()).map_err(|e| e.to_string())?
+        }
+    };
+    let input = fs::read_to_string(&input_file)
+        .map_err(|e| format!("Could not read {}: {e}", input_file.display()))?;
+
+    // Parameters given on the command line win over the ones set next to the expected answers.
+    let mut parameters = get_parameters(&input_file);
+    for (name, value) in &args.params {
+        parameters.set(name, value);
+    }
+
+    measure(day, day_fn, &input, &parameters, args.part, args.repeat)
+        .map_err(|e| format!("Invalid input {}: {e}", input_file.display()))
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("fetch") => return remote::fetch_command(args.skip(1)),
+        Some("submit") => return remote::submit_command(args.skip(1)),
+        Some("scaffold") => return scaffold::scaffold_command(args.skip(1)),
+        _ => {}
+    }
+
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut failed = false;
+    let mut summaries = Vec::with_capacity(args.days.len());
+    for &day in &args.days {
+        match run_day(day, &args) {
+            Ok(summary) => summaries.push(summary),
+            Err(e) => {
+                eprintln!("Day {day:0>2}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    print!("{}", render(&summaries, args.format));
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::solution::Part;
+
+    use crate::report::Format;
+    use std::path::Path;
+
+    use crate::{parse_args, resolve_input, select_variant};
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_single_day_and_part() {
+        let parsed = parse_args(args(&["17", "--part", "2"])).unwrap();
+        assert_eq!(parsed.year, 2024);
+        assert_eq!(parsed.days, vec![17]);
+        assert_eq!(parsed.part, Some(Part::Two));
+        assert_eq!(parsed.input, None);
+        assert_eq!(parsed.variant, None);
+        assert_eq!(parsed.repeat, 1);
+        assert_eq!(parsed.format, Format::Table);
+    }
+
+    #[test]
+    fn test_parse_repeat_and_format() {
+        let parsed = parse_args(args(&["all", "--repeat", "10", "--format", "csv"])).unwrap();
+        assert_eq!(parsed.repeat, 10);
+        assert_eq!(parsed.format, Format::Csv);
+    }
+
+    #[test]
+    fn test_parse_all_and_input() {
+        let parsed = parse_args(args(&["all"])).unwrap();
+        assert_eq!(parsed.days, (1..=25).collect::<Vec<_>>());
+        assert_eq!(parsed.part, None);
+
+        let parsed = parse_args(args(&["14", "--input", "some/file.txt"])).unwrap();
+        assert_eq!(parsed.input.unwrap().to_str(), Some("some/file.txt"));
+
+        assert!(parse_args(args(&["all", "--input", "some/file.txt"])).is_err());
+        assert!(parse_args(args(&["14", "--input", "some/file.txt", "-e", "example"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_example() {
+        let parsed = parse_args(args(&["all", "--example", "example_2"])).unwrap();
+        assert_eq!(parsed.example.as_deref(), Some("example_2"));
+        assert_eq!(parsed.input, None);
+
+        assert_eq!(
+            resolve_input(2024, 1, Some("example")).unwrap(),
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join("d01")
+                .join("input")
+                .join("input_test.txt")
+        );
+        assert!(resolve_input(2024, 1, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let parsed =
+            parse_args(args(&["14", "--param", "width=11", "--param", "height=7"])).unwrap();
+        assert_eq!(
+            parsed.params,
+            [
+                (String::from("width"), String::from("11")),
+                (String::from("height"), String::from("7"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let parsed = parse_args(args(&["3", "--year", "2024"])).unwrap();
+        assert_eq!(parsed.year, 2024);
+        assert_eq!(parsed.days, vec![3]);
+
+        // Days without a crate can be selected but not run.
+        let parsed = parse_args(args(&["3", "-y", "2015"])).unwrap();
+        assert_eq!(parsed.year, 2015);
+        assert!(select_variant(2015, 3, None).is_err());
+        assert!(parse_args(args(&["all", "--year", "2015"])).is_err());
+        assert!(parse_args(args(&["all", "--year", "twenty"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_variant() {
+        let parsed = parse_args(args(&["16", "--variant", "rayon"])).unwrap();
+        assert_eq!(parsed.variant.as_deref(), Some("rayon"));
+
+        assert!(parse_args(args(&["all", "--variant", "rayon"])).is_err());
+        assert!(select_variant(2024, 16, Some("rayon")).is_ok());
+        assert!(select_variant(2024, 16, Some("missing")).is_err());
+        assert!(select_variant(2024, 1, None).is_ok());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["26"])).is_err());
+        assert!(parse_args(args(&["1", "2"])).is_err());
+        assert!(parse_args(args(&["1", "--part", "3"])).is_err());
+        assert!(parse_args(args(&["1", "--part"])).is_err());
+        assert!(parse_args(args(&["1", "--variant"])).is_err());
+        assert!(parse_args(args(&["1", "--param", "width"])).is_err());
+        assert!(parse_args(args(&["1", "--repeat", "0"])).is_err());
+        assert!(parse_args(args(&["1", "--format", "xml"])).is_err());
+    }
+}
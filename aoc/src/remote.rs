@@ -0,0 +1,176 @@
+use std::env;
+use std::process::ExitCode;
+
+use client::backend::{OfflineBackend, UreqBackend};
+use client::session::Session;
+use client::{Client, ClientError};
+use utils::inputs::{InputResolver, DEFAULT_YEAR};
+use utils::solution::Part;
+
+use crate::{day_resolver, parse_days, parse_part, parse_year, USAGE};
+
+/// Set to anything to keep `fetch` and `submit` off the network, only cached files are used.
+pub const OFFLINE_VAR: &str = "AOC_OFFLINE";
+
+pub(crate) fn create_client(resolver: &InputResolver) -> Result<Client, ClientError> {
+    let download_dir = resolver.download_dir();
+    let client = if env::var_os(OFFLINE_VAR).is_some() {
+        Client::new(OfflineBackend, download_dir)
+    } else {
+        Client::new(UreqBackend::new(), download_dir)
+    };
+    Ok(client.with_session(Session::from_env()?))
+}
+
+/// Takes `--year` out of `args`, the rest are positional arguments.
+fn split_year(mut args: impl Iterator<Item = String>) -> Result<(u16, Vec<String>), String> {
+    let mut year = DEFAULT_YEAR;
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => year = parse_year(args.next())?,
+            _ => positional.push(arg),
+        }
+    }
+    Ok((year, positional))
+}
+
+fn parse_fetch_args(args: impl Iterator<Item = String>) -> Result<(u16, Vec<usize>), String> {
+    let (year, args) = split_year(args)?;
+    let mut args = args.into_iter();
+    let days = parse_days(&args.next().ok_or("No day selected")?, year)?;
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument '{arg}'")),
+        None => Ok((year, days)),
+    }
+}
+
+fn parse_submit_args(
+    args: impl Iterator<Item = String>,
+) -> Result<(u16, usize, Part, String), String> {
+    let (year, args) = split_year(args)?;
+    let mut args = args.into_iter();
+    let day = args.next().ok_or("No day selected")?;
+    let [day] = parse_days(&day, year)?[..] else {
+        return Err(String::from(
+            "Answers can only be submitted for a single day",
+        ));
+    };
+    let part = parse_part(&args.next().ok_or("No part selected")?)?;
+    let answer = args.next().ok_or("No answer given")?;
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument '{arg}'")),
+        None => Ok((year, day, part, answer)),
+    }
+}
+
+fn usage_error(e: String) -> ExitCode {
+    eprintln!("{e}");
+    eprintln!("{USAGE}");
+    ExitCode::from(2)
+}
+
+/// Downloads the inputs of the selected days that are not anywhere yet.
+pub fn fetch_command(args: impl Iterator<Item = String>) -> ExitCode {
+    let (year, days) = match parse_fetch_args(args) {
+        Ok(args) => args,
+        Err(e) => return usage_error(e),
+    };
+
+    let client = match create_client(&day_resolver(year, days[0])) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    for day in days {
+        let resolver = day_resolver(year, day);
+        let result = match resolver.input() {
+            Ok(path) => Ok(path),
+            Err(_) => client.fetch_input(resolver.year(), resolver.day()),
+        };
+        match result {
+            Ok(path) => println!("Day {day:0>2}: {}", path.display()),
+            Err(e) => {
+                eprintln!("Day {day:0>2}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+pub fn submit_command(args: impl Iterator<Item = String>) -> ExitCode {
+    let (year, day, part, answer) = match parse_submit_args(args) {
+        Ok(args) => args,
+        Err(e) => return usage_error(e),
+    };
+
+    let resolver = day_resolver(year, day);
+    let level = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+    let outcome = create_client(&resolver)
+        .and_then(|client| client.submit(resolver.year(), resolver.day(), level, &answer));
+
+    match outcome {
+        Ok(outcome) => {
+            println!("Day {day:0>2} part {level}: {outcome}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {day:0>2} part {level}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod remote_test {
+    use utils::solution::Part;
+
+    use crate::remote::{parse_fetch_args, parse_submit_args};
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(parse_fetch_args(args(&["3"])).unwrap(), (2024, vec![3]));
+        assert_eq!(parse_fetch_args(args(&["all"])).unwrap().1.len(), 25);
+        assert_eq!(
+            parse_fetch_args(args(&["3", "--year", "2025"])).unwrap(),
+            (2025, vec![3])
+        );
+        assert!(parse_fetch_args(args(&[])).is_err());
+        assert!(parse_fetch_args(args(&["3", "4"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse_submit_args(args(&["17", "1", "4,6,3"])).unwrap(),
+            (2024, 17, Part::One, String::from("4,6,3"))
+        );
+        assert_eq!(
+            parse_submit_args(args(&["-y", "2025", "1", "2", "42"])).unwrap(),
+            (2025, 1, Part::Two, String::from("42"))
+        );
+        assert!(parse_submit_args(args(&["all", "1", "42"])).is_err());
+        assert!(parse_submit_args(args(&["17", "3", "42"])).is_err());
+        assert!(parse_submit_args(args(&["17", "1"])).is_err());
+    }
+}
@@ -0,0 +1,344 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use utils::error::AocError;
+use utils::solution::{DayFn, DayReport, Parameters, Part, PartReport};
+
+/// How the collected timings are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format '{s}', expected table, json or csv")),
+        }
+    }
+}
+
+/// Minimum and median of all measured runs of one phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseStats {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl PhaseStats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return PhaseStats::default();
+        }
+
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        PhaseStats {
+            min: samples[0],
+            median,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartSummary {
+    pub answer: String,
+    pub stats: PhaseStats,
+}
+
+/// Timings of every phase of a day over all repeats. The answers are taken from the first run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySummary {
+    pub day: usize,
+    pub runs: usize,
+    pub parse: PhaseStats,
+    pub part1: Option<PartSummary>,
+    pub part2: Option<PartSummary>,
+}
+
+/// Runs `day_fn` `repeat` times on `input` and collects the statistics of every phase.
+pub fn measure(
+    day: usize,
+    day_fn: DayFn,
+    input: &str,
+    parameters: &Parameters,
+    part: Option<Part>,
+    repeat: usize,
+) -> Result<DaySummary, AocError> {
+    let repeat = repeat.max(1);
+    let parts = match &part {
+        Some(part) => std::slice::from_ref(part),
+        None => &Part::BOTH,
+    };
+    let reports: Vec<DayReport> = (0..repeat)
+        .map(|_| day_fn(input, parameters, parts))
+        .collect::<Result<_, _>>()?;
+
+    let mut parse_samples: Vec<Duration> = reports.iter().map(|r| r.parse_elapsed).collect();
+
+    Ok(DaySummary {
+        day,
+        runs: repeat,
+        parse: PhaseStats::from_samples(&mut parse_samples),
+        part1: summarize_part(&reports, |r| r.part1.as_ref()),
+        part2: summarize_part(&reports, |r| r.part2.as_ref()),
+    })
+}
+
+fn summarize_part(
+    reports: &[DayReport],
+    part: fn(&DayReport) -> Option<&PartReport>,
+) -> Option<PartSummary> {
+    let first = part(&reports[0])?;
+    let mut samples: Vec<Duration> = reports
+        .iter()
+        .filter_map(|r| part(r).map(|p| p.elapsed))
+        .collect();
+
+    Some(PartSummary {
+        answer: first.answer.clone(),
+        stats: PhaseStats::from_samples(&mut samples),
+    })
+}
+
+pub fn render(summaries: &[DaySummary], format: Format) -> String {
+    match format {
+        Format::Table => render_table(summaries),
+        Format::Json => render_json(summaries),
+        Format::Csv => render_csv(summaries),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:?}")
+}
+
+fn render_table(summaries: &[DaySummary]) -> String {
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Phase"),
+        String::from("Answer"),
+        String::from("Min"),
+        String::from("Median"),
+    ]];
+
+    for summary in summaries {
+        rows.push([
+            format!("{:0>2}", summary.day),
+            String::from("parse"),
+            String::new(),
+            format_duration(summary.parse.min),
+            format_duration(summary.parse.median),
+        ]);
+        for (name, part) in [("part 1", &summary.part1), ("part 2", &summary.part2)] {
+            if let Some(part) = part {
+                rows.push([
+                    format!("{:0>2}", summary.day),
+                    String::from(name),
+                    part.answer.clone(),
+                    format_duration(part.stats.min),
+                    format_duration(part.stats.median),
+                ]);
+            }
+        }
+    }
+
+    let mut widths = [0; 5];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut rtn = String::new();
+    for row in &rows {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        rtn.push_str(line.trim_end());
+        rtn.push('\n');
+    }
+
+    let total: Duration = summaries
+        .iter()
+        .map(|s| {
+            s.parse.median
+                + s.part1.as_ref().map_or(Duration::ZERO, |p| p.stats.median)
+                + s.part2.as_ref().map_or(Duration::ZERO, |p| p.stats.median)
+        })
+        .sum();
+    writeln!(rtn, "Overall median {}", format_duration(total)).unwrap();
+
+    rtn
+}
+
+fn escape_json(value: &str) -> String {
+    let mut rtn = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => rtn.push_str("\\\""),
+            '\\' => rtn.push_str("\\\\"),
+            c if c.is_control() => write!(rtn, "\\u{:04x}", c as u32).unwrap(),
+            c => rtn.push(c),
+        }
+    }
+    rtn
+}
+
+fn render_json_stats(stats: &PhaseStats) -> String {
+    format!(
+        "{{\"min_ns\":{},\"median_ns\":{}}}",
+        stats.min.as_nanos(),
+        stats.median.as_nanos()
+    )
+}
+
+fn render_json_part(part: &Option<PartSummary>) -> String {
+    match part {
+        None => String::from("null"),
+        Some(part) => format!(
+            "{{\"answer\":\"{}\",\"min_ns\":{},\"median_ns\":{}}}",
+            escape_json(&part.answer),
+            part.stats.min.as_nanos(),
+            part.stats.median.as_nanos()
+        ),
+    }
+}
+
+fn render_json(summaries: &[DaySummary]) -> String {
+    let days: Vec<String> = summaries
+        .iter()
+        .map(|s| {
+            format!(
+                "{{\"day\":{},\"runs\":{},\"parse\":{},\"part1\":{},\"part2\":{}}}",
+                s.day,
+                s.runs,
+                render_json_stats(&s.parse),
+                render_json_part(&s.part1),
+                render_json_part(&s.part2)
+            )
+        })
+        .collect();
+
+    format!("[{}]\n", days.join(","))
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(summaries: &[DaySummary]) -> String {
+    let mut rtn = String::from("day,phase,runs,answer,min_ns,median_ns\n");
+
+    for s in summaries {
+        writeln!(
+            rtn,
+            "{},parse,{},,{},{}",
+            s.day,
+            s.runs,
+            s.parse.min.as_nanos(),
+            s.parse.median.as_nanos()
+        )
+        .unwrap();
+        for (name, part) in [("part1", &s.part1), ("part2", &s.part2)] {
+            if let Some(part) = part {
+                writeln!(
+                    rtn,
+                    "{},{name},{},{},{},{}",
+                    s.day,
+                    s.runs,
+                    escape_csv(&part.answer),
+                    part.stats.min.as_nanos(),
+                    part.stats.median.as_nanos()
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    rtn
+}
+
+#[cfg(test)]
+mod report_test {
+    use std::time::Duration;
+
+    use crate::report::{render, DaySummary, Format, PartSummary, PhaseStats};
+
+    fn summary() -> DaySummary {
+        DaySummary {
+            day: 17,
+            runs: 3,
+            parse: PhaseStats {
+                min: Duration::from_nanos(100),
+                median: Duration::from_nanos(150),
+            },
+            part1: Some(PartSummary {
+                answer: String::from("4,6,3"),
+                stats: PhaseStats {
+                    min: Duration::from_nanos(1000),
+                    median: Duration::from_nanos(1200),
+                },
+            }),
+            part2: None,
+        }
+    }
+
+    #[test]
+    fn test_phase_stats() {
+        let mut samples = [5, 1, 3].map(Duration::from_nanos);
+        let stats = PhaseStats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+
+        let mut samples = [4, 1, 3, 8].map(Duration::from_nanos);
+        let stats = PhaseStats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(
+            stats.median,
+            Duration::from_nanos(3) + Duration::from_nanos(1) / 2
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            render(&[summary()], Format::Json),
+            "[{\"day\":17,\"runs\":3,\"parse\":{\"min_ns\":100,\"median_ns\":150},\
+             \"part1\":{\"answer\":\"4,6,3\",\"min_ns\":1000,\"median_ns\":1200},\
+             \"part2\":null}]\n"
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            render(&[summary()], Format::Csv),
+            "day,phase,runs,answer,min_ns,median_ns\n\
+             17,parse,3,,100,150\n\
+             17,part1,3,\"4,6,3\",1000,1200\n"
+        );
+    }
+}
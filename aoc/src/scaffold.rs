@@ -0,0 +1,484 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use utils::inputs::{InputResolver, DEFAULT_YEAR};
+
+use crate::remote::create_client;
+use crate::USAGE;
+
+const CARGO_TEMPLATE: &str = include_str!("../../template/cargo.toml");
+const MAIN_TEMPLATE: &str = include_str!("../../template/main.rs");
+const LIB_TEMPLATE: &str = include_str!("../../template/lib.rs");
+const LIB_SINGLE_LINE_TEMPLATE: &str = include_str!("../../template/lib_single_line.rs");
+const LIB_NUMBERS_TEMPLATE: &str = include_str!("../../template/lib_util_numbers.rs");
+const ANSWERS_TEMPLATE: &str = include_str!("../../template/answers.txt");
+
+/// The `lib.rs` templates, one per input shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// A `Vec<String>` with one entry per line.
+    Lines,
+    /// The whole input as one `String`.
+    SingleLine,
+    /// A `Vec<usize>` with one number per line.
+    Numbers,
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lines" => Ok(Template::Lines),
+            "single-line" => Ok(Template::SingleLine),
+            "numbers" => Ok(Template::Numbers),
+            _ => Err(format!(
+                "Invalid template '{s}', expected lines, single-line or numbers"
+            )),
+        }
+    }
+}
+
+impl Template {
+    /// Picks the template that fits the shape of `input`.
+    pub fn detect(input: &str) -> Self {
+        let lines: Vec<&str> = input
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+
+        if lines.len() == 1 {
+            Template::SingleLine
+        } else if !lines.is_empty() && lines.iter().all(|l| l.parse::<usize>().is_ok()) {
+            Template::Numbers
+        } else {
+            Template::Lines
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Template::Lines => LIB_TEMPLATE,
+            Template::SingleLine => LIB_SINGLE_LINE_TEMPLATE,
+            Template::Numbers => LIB_NUMBERS_TEMPLATE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaffoldOptions {
+    pub year: u16,
+    pub day: u8,
+    /// Detected from the input if `None`, or [Template::Lines] without an input.
+    pub template: Option<Template>,
+    /// Downloads the input before picking the template.
+    pub fetch: bool,
+}
+
+/// Folder of a day crate relative to the workspace. Days of [DEFAULT_YEAR] live in the workspace
+/// root, all other years in a folder of their own.
+pub fn crate_path(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("d{day:0>2}")
+    } else {
+        format!("{year}/d{day:0>2}")
+    }
+}
+
+/// Package name of a day crate, which has to be unique in the workspace.
+pub fn package_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("d{day:0>2}")
+    } else {
+        format!("d{day:0>2}_{year}")
+    }
+}
+
+/// Sort key of workspace members that are day crates, `None` for everything else.
+fn day_key(member: &str) -> Option<(u16, u8)> {
+    let (year, day) = match member.split_once('/') {
+        Some((year, day)) => (year.parse().ok()?, day),
+        None => (DEFAULT_YEAR, member),
+    };
+    let day = day.strip_prefix('d')?;
+    if day.len() != 2 {
+        return None;
+    }
+    Some((year, day.parse().ok()?))
+}
+
+/// Adds `member` to the `members` of the workspace manifest, sorted in with the other days.
+pub fn register_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest
+        .find("members")
+        .ok_or("The workspace manifest has no members")?;
+    let open = start + manifest[start..].find('[').ok_or("Malformed members")?;
+    let close = open + manifest[open..].find(']').ok_or("Malformed members")?;
+
+    let mut members: Vec<&str> = manifest[open + 1..close]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+
+    let key = day_key(member);
+    let position = members
+        .iter()
+        .position(|m| day_key(m).is_some_and(|k| Some(k) > key))
+        .or_else(|| {
+            members
+                .iter()
+                .rposition(|m| day_key(m).is_some())
+                .map(|p| p + 1)
+        })
+        .unwrap_or(members.len());
+    members.insert(position, member);
+
+    let members: Vec<String> = members.iter().map(|m| format!("  \"{m}\"")).collect();
+    Ok(format!(
+        "{}[\n{}\n{}",
+        &manifest[..open],
+        members.join(",\n"),
+        &manifest[close..]
+    ))
+}
+
+/// Adds the day crate `package` in `path` to the `[dependencies]` of the `aoc` manifest.
+pub fn register_dependency(manifest: &str, package: &str, path: &str) -> Result<String, String> {
+    if manifest
+        .lines()
+        .any(|l| l.starts_with(&format!("{package} =")))
+    {
+        return Ok(manifest.to_string());
+    }
+
+    let start = manifest
+        .find("[dependencies]")
+        .ok_or("The aoc manifest has no dependencies")?;
+    let end = manifest[start..]
+        .find("\n[")
+        .map_or(manifest.len(), |end| start + end + 1);
+    let before = manifest[..end].trim_end_matches('\n');
+    Ok(format!(
+        "{before}\n{package} = {{path = \"{path}\"}}\n{}",
+        &manifest[end..]
+    ))
+}
+
+/// Adds `day` of `year` to the `DAYS` table in the source of `aoc`, sorted in with the others.
+pub fn register_day(source: &str, year: u16, day: u8, package: &str) -> Result<String, String> {
+    let entry = format!("    ({year}, {day}, {package}::variants),");
+    if source.lines().any(|l| l == entry) {
+        return Ok(source.to_string());
+    }
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.contains("const DAYS"))
+        .ok_or("The aoc source has no DAYS")?;
+    let close = start
+        + lines[start..]
+            .iter()
+            .position(|l| *l == "];")
+            .ok_or("Malformed DAYS")?;
+
+    let entry_key = |line: &str| -> Option<(u16, u8)> {
+        let mut values = line.trim().strip_prefix('(')?.split(',');
+        Some((
+            values.next()?.trim().parse().ok()?,
+            values.next()?.trim().parse().ok()?,
+        ))
+    };
+    let position = (start + 1..close)
+        .find(|&i| entry_key(lines[i]).is_some_and(|key| key > (year, day)))
+        .unwrap_or(close);
+    lines.insert(position, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn fill_template(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("DayXX", &format!("Day{day:0>2}"))
+        .replace("dXX", &package_name(year, day))
+}
+
+/// Writes a new day crate into `workspace_dir`, registers it as a workspace member and adds it to
+/// the days that `aoc` can run.
+pub fn scaffold(workspace_dir: &Path, options: &ScaffoldOptions) -> Result<PathBuf, String> {
+    let (year, day) = (options.year, options.day);
+    let member = crate_path(year, day);
+    let crate_dir = workspace_dir.join(&member);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    let resolver = InputResolver::for_crate(&crate_dir);
+    if options.fetch {
+        let client = create_client(&resolver).map_err(|e| e.to_string())?;
+        client
+            .fetch_input(year, day)
+            .map_err(|e| format!("Could not fetch the input: {e}"))?;
+    }
+    let template = options.template.unwrap_or_else(|| {
+        resolver
+            .input()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map_or(Template::Lines, |input| Template::detect(&input))
+    });
+
+    let manifest_path = workspace_dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Could not read {}: {e}", manifest_path.display()))?;
+    let manifest = register_member(&manifest, &member)?;
+
+    let package = package_name(year, day);
+    let aoc_manifest_path = workspace_dir.join("aoc").join("Cargo.toml");
+    let aoc_manifest = fs::read_to_string(&aoc_manifest_path)
+        .map_err(|e| format!("Could not read {}: {e}", aoc_manifest_path.display()))?;
+    let aoc_manifest = register_dependency(&aoc_manifest, &package, &format!("../{member}"))?;
+    let days_path = workspace_dir.join("aoc").join("src").join("days.rs");
+    let days = fs::read_to_string(&days_path)
+        .map_err(|e| format!("Could not read {}: {e}", days_path.display()))?;
+    let days = register_day(&days, year, day, &package)?;
+
+    let utils_path = if year == DEFAULT_YEAR {
+        "../utils"
+    } else {
+        "../../utils"
+    };
+    let files = [
+        (
+            PathBuf::from("Cargo.toml"),
+            fill_template(CARGO_TEMPLATE, year, day).replace("../utils", utils_path),
+        ),
+        (
+            Path::new("src").join("main.rs"),
+            fill_template(MAIN_TEMPLATE, year, day),
+        ),
+        (
+            Path::new("src").join("lib.rs"),
+            fill_template(template.source(), year, day),
+        ),
+        (Path::new("input").join("input_test.txt"), String::new()),
+        (
+            Path::new("input").join("answers.txt"),
+            format!("{ANSWERS_TEMPLATE}input_test.txt 1 TODO\ninput_test.txt 2 TODO\n"),
+        ),
+    ];
+
+    let write_error =
+        |path: &Path, e: std::io::Error| format!("Could not write {}: {e}", path.display());
+    for (file, content) in files {
+        let path = crate_dir.join(file);
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| write_error(&path, e))?;
+        fs::write(&path, content).map_err(|e| write_error(&path, e))?;
+    }
+    for (path, content) in [
+        (manifest_path, manifest),
+        (aoc_manifest_path, aoc_manifest),
+        (days_path, days),
+    ] {
+        fs::write(&path, content).map_err(|e| write_error(&path, e))?;
+    }
+
+    Ok(crate_dir)
+}
+
+fn parse_scaffold_args(mut args: impl Iterator<Item = String>) -> Result<ScaffoldOptions, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut day = None;
+    let mut template = None;
+    let mut fetch = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let value = args.next().ok_or("Missing value for --year")?;
+                year = value
+                    .parse()
+                    .map_err(|_| format!("Invalid year '{value}'"))?;
+            }
+            "--template" | "-t" => {
+                let value = args.next().ok_or("Missing value for --template")?;
+                template = Some(value.parse()?);
+            }
+            "--fetch" => fetch = true,
+            _ if day.is_some() => return Err(format!("Unexpected argument '{arg}'")),
+            _ => match arg.parse() {
+                Ok(d) if (1..=25).contains(&d) => day = Some(d),
+                _ => return Err(format!("Invalid day '{arg}', expected 1 to 25")),
+            },
+        }
+    }
+
+    Ok(ScaffoldOptions {
+        year,
+        day: day.ok_or("No day selected")?,
+        template,
+        fetch,
+    })
+}
+
+pub fn scaffold_command(args: impl Iterator<Item = String>) -> ExitCode {
+    let options = match parse_scaffold_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    match scaffold(workspace_dir, &options) {
+        Ok(crate_dir) => {
+            println!("Created {}", crate_dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod scaffold_test {
+    use std::env;
+    use std::fs;
+
+    use crate::scaffold::{
+        parse_scaffold_args, register_day, register_dependency, register_member, scaffold,
+        ScaffoldOptions, Template,
+    };
+
+    const MANIFEST: &str = "[workspace]\nresolver = \"2\"\nmembers  = [\n  \"d01\",\n  \"d03\",\n  \"utils\",\n  \"aoc\"\n]\n";
+    const AOC_MANIFEST: &str =
+        "[package]\nname = \"aoc\"\n\n[dependencies]\nutils = {path = \"../utils\"}\nd01 = {path = \"../d01\"}\n";
+    const DAYS: &str = "use utils::solution::Variant;\n\npub const DAYS: &[(u16, u8, VariantsFn)] = &[\n    (2024, 1, d01::variants),\n    (2024, 3, d03::variants),\n];\n\npub fn find_day() {}\n";
+
+    #[test]
+    fn test_detect_template() {
+        assert_eq!(
+            Template::detect("xmul(2,4)&mul[3,7]\n"),
+            Template::SingleLine
+        );
+        assert_eq!(Template::detect("1\n10\n100\n2024\n"), Template::Numbers);
+        assert_eq!(Template::detect("3   4\n4   3\n"), Template::Lines);
+        assert_eq!(Template::detect(""), Template::Lines);
+    }
+
+    #[test]
+    fn test_register_member() {
+        let manifest = register_member(MANIFEST, "d02").unwrap();
+        assert!(manifest.contains("  \"d01\",\n  \"d02\",\n  \"d03\",\n  \"utils\""));
+
+        let manifest = register_member(&manifest, "2025/d01").unwrap();
+        assert!(manifest.contains("  \"d03\",\n  \"2025/d01\",\n  \"utils\""));
+        assert!(manifest.ends_with("  \"aoc\"\n]\n"));
+
+        assert_eq!(register_member(&manifest, "d02").unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_register_dependency() {
+        let manifest = register_dependency(AOC_MANIFEST, "d01_2025", "../2025/d01").unwrap();
+        assert!(
+            manifest.ends_with("d01 = {path = \"../d01\"}\nd01_2025 = {path = \"../2025/d01\"}\n")
+        );
+        assert_eq!(
+            register_dependency(&manifest, "d01_2025", "../2025/d01").unwrap(),
+            manifest
+        );
+
+        let manifest = register_dependency(
+            "[dependencies]\nutils = {path = \"../utils\"}\n\n[dev-dependencies]\n",
+            "d02",
+            "../d02",
+        )
+        .unwrap();
+        assert!(manifest.contains("utils = {path = \"../utils\"}\nd02 = {path = \"../d02\"}\n["));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days = register_day(DAYS, 2024, 2, "d02").unwrap();
+        assert!(days.contains(
+            "    (2024, 1, d01::variants),\n    (2024, 2, d02::variants),\n    (2024, 3, d03::variants),\n"
+        ));
+
+        let days = register_day(&days, 2025, 1, "d01_2025").unwrap();
+        assert!(
+            days.contains("    (2024, 3, d03::variants),\n    (2025, 1, d01_2025::variants),\n];")
+        );
+        assert!(days.ends_with("pub fn find_day() {}\n"));
+
+        assert_eq!(register_day(&days, 2024, 2, "d02").unwrap(), days);
+    }
+
+    #[test]
+    fn test_parse_scaffold_args() {
+        let args = ["7", "--year", "2025", "--template", "numbers"].map(String::from);
+        assert_eq!(
+            parse_scaffold_args(args.into_iter()).unwrap(),
+            ScaffoldOptions {
+                year: 2025,
+                day: 7,
+                template: Some(Template::Numbers),
+                fetch: false,
+            }
+        );
+        assert!(parse_scaffold_args(["26"].map(String::from).into_iter()).is_err());
+        assert!(parse_scaffold_args(["1", "-t", "grid"].map(String::from).into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_scaffold_year_crate() {
+        let workspace = env::temp_dir().join(format!("aoc_scaffold_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(&workspace).unwrap();
+        fs::write(workspace.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::create_dir_all(workspace.join("aoc").join("src")).unwrap();
+        fs::write(workspace.join("aoc").join("Cargo.toml"), AOC_MANIFEST).unwrap();
+        fs::write(workspace.join("aoc").join("src").join("days.rs"), DAYS).unwrap();
+
+        let options = ScaffoldOptions {
+            year: 2025,
+            day: 4,
+            template: None,
+            fetch: false,
+        };
+        let crate_dir = scaffold(&workspace, &options).unwrap();
+        assert_eq!(crate_dir, workspace.join("2025").join("d04"));
+
+        let cargo = fs::read_to_string(crate_dir.join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"d04_2025\""));
+        assert!(cargo.contains("utils = {path = \"../../utils\"}"));
+        let main = fs::read_to_string(crate_dir.join("src").join("main.rs")).unwrap();
+        assert!(main.contains("use d04_2025::Day04;"));
+        let lib = fs::read_to_string(crate_dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("type Input = Vec<String>;"));
+        assert!(crate_dir.join("input").join("input_test.txt").is_file());
+        let answers = fs::read_to_string(crate_dir.join("input").join("answers.txt")).unwrap();
+        assert!(answers.contains("input_test.txt 1 TODO"));
+        let manifest = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("\"2025/d04\""));
+        let aoc_manifest = fs::read_to_string(workspace.join("aoc").join("Cargo.toml")).unwrap();
+        assert!(aoc_manifest.contains("d04_2025 = {path = \"../2025/d04\"}"));
+        let days = fs::read_to_string(workspace.join("aoc").join("src").join("days.rs")).unwrap();
+        assert!(days.contains("    (2025, 4, d04_2025::variants),\n];"));
+
+        assert!(scaffold(&workspace, &options).is_err());
+
+        fs::remove_dir_all(workspace).unwrap();
+    }
+}
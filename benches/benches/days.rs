@@ -0,0 +1,45 @@
+use benches::bench_variants;
+use criterion::{criterion_group, criterion_main, Criterion};
+use utils::solution::Variant;
+
+// Every variant a day lists is benchmarked, so new alternates show up without touching this file.
+const DAYS: [fn() -> Vec<Variant>; 25] = [
+    d01::variants,
+    d02::variants,
+    d03::variants,
+    d04::variants,
+    d05::variants,
+    d06::variants,
+    d07::variants,
+    d08::variants,
+    d09::variants,
+    d10::variants,
+    d11::variants,
+    d12::variants,
+    d13::variants,
+    d14::variants,
+    d15::variants,
+    d16::variants,
+    d17::variants,
+    d18::variants,
+    d19::variants,
+    d20::variants,
+    d21::variants,
+    d22::variants,
+    d23::variants,
+    d24::variants,
+    d25::variants,
+];
+
+fn days(c: &mut Criterion) {
+    for (index, variants) in DAYS.iter().enumerate() {
+        bench_variants(c, index + 1, &variants());
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
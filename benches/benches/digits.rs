@@ -0,0 +1,91 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use utils::digits::{concatenate, digit_count};
+
+// The loops the days used before utils::digits, kept here so both can be compared.
+fn legacy_digit_count(mut number: u64) -> u64 {
+    let mut rtn = 1;
+    number /= 10;
+    while number != 0 {
+        rtn += 1;
+        number /= 10;
+    }
+    rtn
+}
+
+fn legacy_concatenate(mut left: u64, right: u64) -> u64 {
+    let mut r = right / 10;
+    left *= 10;
+    while r > 0 {
+        left *= 10;
+        r /= 10;
+    }
+    left + right
+}
+
+/// Numbers with 1 to 19 digits, so every length shows up equally often.
+fn numbers() -> Vec<u64> {
+    (0..19)
+        .flat_map(|exponent| {
+            let base = 10_u64.pow(exponent);
+            [base, base + base / 3, base * 5 - 1]
+        })
+        .collect()
+}
+
+fn digits(c: &mut Criterion) {
+    let numbers = numbers();
+
+    let mut group = c.benchmark_group("digits/digit_count");
+    group.bench_function("legacy", |b| {
+        b.iter(|| {
+            numbers
+                .iter()
+                .map(|&n| legacy_digit_count(black_box(n)))
+                .sum::<u64>()
+        })
+    });
+    group.bench_function("ilog10", |b| {
+        b.iter(|| {
+            numbers
+                .iter()
+                .map(|&n| digit_count(black_box(n)) as u64)
+                .sum::<u64>()
+        })
+    });
+    group.finish();
+
+    // Small enough on the left that no concatenation overflows.
+    let pairs: Vec<(u64, u64)> = numbers
+        .iter()
+        .filter(|&&n| n < 1_000_000_000)
+        .map(|&n| (n % 1000, n))
+        .collect();
+
+    let mut group = c.benchmark_group("digits/concatenate");
+    group.bench_function("legacy", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .map(|&(l, r)| legacy_concatenate(black_box(l), black_box(r)))
+                .fold(0, u64::wrapping_add)
+        })
+    });
+    group.bench_function("ilog10", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .map(|&(l, r)| concatenate(black_box(l), black_box(r)).unwrap())
+                .fold(0, u64::wrapping_add)
+        })
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = digits
+}
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Duration;
+
+use criterion::Criterion;
+use utils::inputs::InputResolver;
+use utils::solution::{read_input, DayReport, Parameters, Part, Variant};
+
+/// Reads the real input of `day`, or `None` if it is not checked out.
+pub fn read_day_input(day: usize) -> Option<String> {
+    let day_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(format!("d{day:0>2}"));
+    let input = InputResolver::for_crate(day_dir)
+        .input()
+        .and_then(|input_file| read_input(&input_file));
+
+    match input {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("Skipping day {day:0>2}, {e}");
+            None
+        }
+    }
+}
+
+/// Benchmarks parse, part 1 and part 2 of every variant of `day` on its real input.
+///
+/// The benchmarks are named `dXX/<phase>/<variant>`, so all variants of a day end up in the same
+/// groups and criterion reports them side by side. A variant only runs through its
+/// [DayFn](utils::solution::DayFn), so every phase is timed with the durations of the
+/// [DayReport] it returns. The parse benchmarks solve no part at all, the part benchmarks only
+/// count the time of their part. A variant that fails on the input is reported and skipped.
+pub fn bench_variants(c: &mut Criterion, day: usize, variants: &[Variant]) {
+    let Some(input) = read_day_input(day) else {
+        return;
+    };
+
+    // The real inputs run with the parameters every day falls back to.
+    let parameters = Parameters::default();
+    let prefix = format!("d{day:0>2}");
+    let variants: Vec<Variant> = variants
+        .iter()
+        .filter(
+            |(name, day_fn)| match day_fn(&input, &parameters, &Part::BOTH) {
+                Ok(_) => true,
+                Err(e) => {
+                    eprintln!(
+                        "Skipping variant {name} of day {day:0>2}, the input is invalid: {e}"
+                    );
+                    false
+                }
+            },
+        )
+        .copied()
+        .collect();
+
+    for (phase, parts) in [
+        ("parse", &[][..]),
+        ("part1", &[Part::One][..]),
+        ("part2", &[Part::Two][..]),
+    ] {
+        let mut group = c.benchmark_group(format!("{prefix}/{phase}"));
+        for &(name, day_fn) in &variants {
+            group.bench_function(name, |b| {
+                b.iter_custom(|iterations| {
+                    (0..iterations)
+                        .map(|_| {
+                            let report = day_fn(black_box(&input), &parameters, parts)
+                                .expect("the variant solved the input before");
+                            phase_elapsed(&report, phase)
+                        })
+                        .sum()
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+fn phase_elapsed(report: &DayReport, phase: &str) -> Duration {
+    let part = match phase {
+        "parse" => return report.parse_elapsed,
+        "part1" => &report.part1,
+        _ => &report.part2,
+    };
+    part.as_ref().expect("the phase was requested").elapsed
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use crate::ClientError;
+
+const USER_AGENT: &str = "github.com/Accessory/aoc2024";
+
+/// Response of the server, reduced to what the client needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends the requests of [crate::Client], so tests and offline runs can swap out the network.
+pub trait HttpBackend {
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError>;
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError>;
+}
+
+/// Talks to the real server, or any other HTTP server like a local mock.
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+impl UreqBackend {
+    pub fn new() -> Self {
+        UreqBackend {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl Default for UreqBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, ClientError> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(e)) => return Err(ClientError::Transport(e.to_string())),
+    };
+
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
+
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError> {
+        into_response(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={session}"))
+                .call(),
+        )
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError> {
+        into_response(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={session}"))
+                .send_form(form),
+        )
+    }
+}
+
+/// Never touches the network. Inputs and answers that are already cached still work.
+pub struct OfflineBackend;
+
+impl HttpBackend for OfflineBackend {
+    fn get(&self, _url: &str, _session: &str) -> Result<Response, ClientError> {
+        Err(ClientError::Offline)
+    }
+
+    fn post_form(
+        &self,
+        _url: &str,
+        _session: &str,
+        _form: &[(&str, &str)],
+    ) -> Result<Response, ClientError> {
+        Err(ClientError::Offline)
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::backend::HttpBackend;
+use crate::session::Session;
+use crate::submission::{cache_outcome, cached_outcome, parse_outcome, Outcome};
+use crate::throttle::Throttle;
+
+pub mod backend;
+pub mod session;
+pub mod submission;
+pub mod throttle;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Delay between two requests to the server.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    /// No session token in the environment or the session file.
+    MissingSession,
+    /// The backend is offline and the file is not cached.
+    Offline,
+    Status {
+        url: String,
+        status: u16,
+    },
+    Transport(String),
+    UnexpectedResponse(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Io(e) => write!(f, "{e}"),
+            ClientError::MissingSession => write!(
+                f,
+                "no session token, set {} or write it to ~/.config/aoc/session or the file in {}",
+                session::SESSION_VAR,
+                session::SESSION_FILE_VAR
+            ),
+            ClientError::Offline => write!(f, "offline and not cached"),
+            ClientError::Status { url, status } => write!(f, "{url} answered with {status}"),
+            ClientError::Transport(e) => write!(f, "{e}"),
+            ClientError::UnexpectedResponse(e) => write!(f, "unexpected response, {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(value: io::Error) -> Self {
+        ClientError::Io(value)
+    }
+}
+
+/// Downloads inputs and submits answers, caching both on disk.
+///
+/// Inputs are stored in `<cache dir>/<year>/<day>.txt`, the layout that
+/// `utils::inputs::InputResolver` reads, and are never downloaded twice. Verdicts on answers go
+/// to `<cache dir>/<year>/<day>_submissions.txt`, so an answer is never submitted twice.
+pub struct Client {
+    backend: Box<dyn HttpBackend>,
+    base_url: String,
+    session: Option<Session>,
+    cache_dir: PathBuf,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(backend: impl HttpBackend + 'static, cache_dir: impl Into<PathBuf>) -> Self {
+        Client {
+            backend: Box::new(backend),
+            base_url: String::from(BASE_URL),
+            session: None,
+            cache_dir: cache_dir.into(),
+            throttle: Throttle::new(MIN_REQUEST_INTERVAL),
+        }
+    }
+
+    pub fn with_session(mut self, session: Option<Session>) -> Self {
+        self.session = session;
+        self
+    }
+
+    /// Sends the requests somewhere else than [BASE_URL], like a local mock server.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.throttle = Throttle::new(min_interval);
+        self
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("{day:0>2}.txt"))
+    }
+
+    fn submissions_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("{day:0>2}_submissions.txt"))
+    }
+
+    fn session(&self) -> Result<&str, ClientError> {
+        self.session
+            .as_ref()
+            .map(Session::token)
+            .ok_or(ClientError::MissingSession)
+    }
+
+    /// Path of the input of `day`, downloading it first if it is not cached yet.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<PathBuf, ClientError> {
+        let path = self.input_path(year, day);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let session = self.session()?;
+        self.throttle.wait();
+        let response = self.backend.get(&url, session)?;
+        if response.status != 200 {
+            return Err(ClientError::Status {
+                url,
+                status: response.status,
+            });
+        }
+
+        // Write next to the target first, so an interrupted download never looks cached.
+        fs::create_dir_all(path.parent().unwrap())?;
+        let partial = path.with_extension("part");
+        fs::write(&partial, response.body)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(path)
+    }
+
+    /// Submits `answer` for `part` of `day`, unless the same answer got a verdict before.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, ClientError> {
+        let submissions = self.submissions_path(year, day);
+        if let Some(outcome) = cached_outcome(&submissions, part, answer)? {
+            return Ok(outcome);
+        }
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let session = self.session()?;
+        self.throttle.wait();
+        let level = part.to_string();
+        let response =
+            self.backend
+                .post_form(&url, session, &[("level", &level), ("answer", answer)])?;
+        if response.status != 200 {
+            return Err(ClientError::Status {
+                url,
+                status: response.status,
+            });
+        }
+
+        let outcome = parse_outcome(&response.body)?;
+        cache_outcome(&submissions, part, answer, &outcome)?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod client_test {
+    use std::env;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::backend::{OfflineBackend, UreqBackend};
+    use crate::session::Session;
+    use crate::submission::{parse_outcome, Outcome};
+    use crate::{Client, ClientError};
+
+    /// Serves `responses` in order, one per connection, and reports every request it got as
+    /// `<request line>\n<cookie>\n<body>`.
+    fn mock_server(responses: Vec<&'static str>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for body in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut cookie = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = value.to_string(),
+                        "content-length" => content_length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+
+                sender
+                    .send(format!(
+                        "{}\n{cookie}\n{}",
+                        request_line.trim_end(),
+                        String::from_utf8(request_body).unwrap()
+                    ))
+                    .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_client_test_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn client(base_url: &str, cache_dir: &PathBuf) -> Client {
+        Client::new(UreqBackend::new(), cache_dir)
+            .with_base_url(base_url)
+            .with_session(Some(Session::new("secret\n")))
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let (base_url, requests) = mock_server(vec!["1 2\n3 4\n"]);
+        let cache_dir = cache_dir("fetch");
+        let client = client(&base_url, &cache_dir);
+
+        let path = client.fetch_input(2024, 1).unwrap();
+        assert_eq!(path, cache_dir.join("2024").join("01.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /2024/day/1/input HTTP/1.1\nsession=secret\n"
+        );
+
+        // The server only answers once, a second download would fail.
+        assert_eq!(client.fetch_input(2024, 1).unwrap(), path);
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit_caches_verdict() {
+        let (base_url, requests) = mock_server(vec![
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        ]);
+        let cache_dir = cache_dir("submit");
+        let client = client(&base_url, &cache_dir);
+
+        assert_eq!(client.submit(2024, 3, 2, "48").unwrap(), Outcome::TooLow);
+        assert_eq!(
+            requests.recv().unwrap(),
+            "POST /2024/day/3/answer HTTP/1.1\nsession=secret\nlevel=2&answer=48"
+        );
+
+        assert_eq!(client.submit(2024, 3, 2, "48").unwrap(), Outcome::TooLow);
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_offline_and_missing_session() {
+        let cache_dir = cache_dir("offline");
+
+        let offline = Client::new(OfflineBackend, &cache_dir).with_session(Some(Session::new("s")));
+        assert!(matches!(
+            offline.fetch_input(2024, 5),
+            Err(ClientError::Offline)
+        ));
+
+        let no_session = Client::new(OfflineBackend, &cache_dir);
+        assert!(matches!(
+            no_session.submit(2024, 5, 1, "143"),
+            Err(ClientError::MissingSession)
+        ));
+
+        // A cached input needs neither the network nor a session.
+        fs::create_dir_all(cache_dir.join("2024")).unwrap();
+        fs::write(cache_dir.join("2024").join("05.txt"), "47|53\n").unwrap();
+        assert!(no_session.fetch_input(2024, 5).is_ok());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            parse_outcome("<p>That's the right answer! You are one gold star closer.</p>").unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_outcome("<p>That's not the right answer; your answer is too high.</p>").unwrap(),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_outcome("<p>That's not the right answer. If you're stuck</p>").unwrap(),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            parse_outcome("<p>You gave an answer too recently. You have 34s left to wait.</p>")
+                .unwrap(),
+            Outcome::TooSoon {
+                wait: String::from("34s")
+            }
+        );
+        assert_eq!(
+            parse_outcome("<p>You don't seem to be solving the right level.</p>").unwrap(),
+            Outcome::WrongLevel
+        );
+        assert!(parse_outcome("<html></html>").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use crate::ClientError;
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable pointing at a file with the session token, `~/.config/aoc/session` if
+/// unset.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// Session cookie of the logged in account. Debug output hides the token.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
+
+impl Debug for Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session(***)")
+    }
+}
+
+impl Session {
+    pub fn new(token: &str) -> Self {
+        Session(token.trim().to_string())
+    }
+
+    pub fn token(&self) -> &str {
+        &self.0
+    }
+
+    /// Reads the token from `path`. A missing or empty file is no session.
+    pub fn from_file(path: &Path) -> Result<Option<Session>, ClientError> {
+        match fs::read_to_string(path) {
+            Ok(token) if !token.trim().is_empty() => Ok(Some(Session::new(&token))),
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Takes the token from [SESSION_VAR], or else from the session file.
+    pub fn from_env() -> Result<Option<Session>, ClientError> {
+        if let Ok(token) = env::var(SESSION_VAR) {
+            if !token.trim().is_empty() {
+                return Ok(Some(Session::new(&token)));
+            }
+        }
+
+        match session_file() {
+            Some(path) => Session::from_file(&path),
+            None => Ok(None),
+        }
+    }
+}
+
+fn session_file() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(SESSION_FILE_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    let home = env::var_os("HOME")?;
+    Some(
+        PathBuf::from(home)
+            .join(".config")
+            .join("aoc")
+            .join("session"),
+    )
+}
@@ -0,0 +1,132 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::ClientError;
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The last answer was submitted too recently, `wait` is the time left as the server wrote it.
+    TooSoon {
+        wait: String,
+    },
+    /// The part was already solved, or its first part is still open.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Only verdicts about the answer itself are cached, the others can change on a retry.
+    fn cache_key(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::TooHigh => Some("too_high"),
+            Outcome::TooLow => Some("too_low"),
+            Outcome::Wrong => Some("wrong"),
+            Outcome::TooSoon { .. } | Outcome::WrongLevel => None,
+        }
+    }
+
+    fn from_cache_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Outcome::Correct),
+            "too_high" => Some(Outcome::TooHigh),
+            "too_low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer"),
+            Outcome::TooHigh => write!(f, "Wrong answer, too high"),
+            Outcome::TooLow => write!(f, "Wrong answer, too low"),
+            Outcome::Wrong => write!(f, "Wrong answer"),
+            Outcome::TooSoon { wait } => write!(f, "Answered too recently, wait {wait}"),
+            Outcome::WrongLevel => write!(f, "This part is already solved or not unlocked yet"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the server answers a submission with.
+pub fn parse_outcome(body: &str) -> Result<Outcome, ClientError> {
+    if body.contains("That's the right answer") {
+        return Ok(Outcome::Correct);
+    }
+    if body.contains("That's not the right answer") {
+        return Ok(if body.contains("too high") {
+            Outcome::TooHigh
+        } else if body.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        });
+    }
+    if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map_or("a moment", |(wait, _)| wait);
+        return Ok(Outcome::TooSoon {
+            wait: wait.to_string(),
+        });
+    }
+    if body.contains("You don't seem to be solving the right level") {
+        return Ok(Outcome::WrongLevel);
+    }
+
+    Err(ClientError::UnexpectedResponse(String::from(
+        "no verdict in the answer page",
+    )))
+}
+
+/// Looks up an earlier verdict for `answer` of `part` in the submissions file. Every line of the
+/// file has the form `<part> <outcome> <answer>`.
+pub fn cached_outcome(
+    submissions_file: &Path,
+    part: u8,
+    answer: &str,
+) -> Result<Option<Outcome>, ClientError> {
+    let content = match fs::read_to_string(submissions_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(content.lines().find_map(|line| {
+        let mut split = line.splitn(3, ' ');
+        let cached_part: u8 = split.next()?.parse().ok()?;
+        let outcome = Outcome::from_cache_key(split.next()?)?;
+        let cached_answer = split.next()?;
+        (cached_part == part && cached_answer == answer).then_some(outcome)
+    }))
+}
+
+pub fn cache_outcome(
+    submissions_file: &Path,
+    part: u8,
+    answer: &str,
+    outcome: &Outcome,
+) -> Result<(), ClientError> {
+    let Some(key) = outcome.cache_key() else {
+        return Ok(());
+    };
+
+    if let Some(parent) = submissions_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(submissions_file)?;
+    writeln!(file, "{part} {key} {answer}")?;
+    Ok(())
+}
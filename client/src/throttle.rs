@@ -0,0 +1,30 @@
+use std::cell::Cell;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Keeps a minimum delay between two requests to the server.
+#[derive(Debug)]
+pub struct Throttle {
+    min_interval: Duration,
+    last: Cell<Option<Instant>>,
+}
+
+impl Throttle {
+    pub fn new(min_interval: Duration) -> Self {
+        Throttle {
+            min_interval,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Sleeps until the next request is allowed and counts it as sent.
+    pub fn wait(&self) {
+        if let Some(last) = self.last.get() {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last.set(Some(Instant::now()));
+    }
+}
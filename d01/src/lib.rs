@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
+use utils::error::{input_lines, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<usize>, Vec<usize>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for line in input_lines(input) {
+            let mut numbers = line.text.split_ascii_whitespace();
+            let left_number: usize = line.parse_next(&mut numbers, "left number")?;
+            let right_number: usize = line.parse_next(&mut numbers, "right number")?;
+
+            left.push(left_number);
+            right.push(right_number)
+        }
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> Self::Answer1 {
+        part1(left, right)
+    }
+
+    fn part2((left, right): &Self::Input) -> Self::Answer2 {
+        part2(left, right)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day01>)]
+}
+
+pub fn part1(left: &[usize], right: &[usize]) -> usize {
+    // Preamble
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+
+    // Solve
+    left.sort_unstable();
+    right.sort_unstable();
+    let mut result = 0;
+
+    for (l, r) in left.iter().zip(right.iter()) {
+        let distance = l.abs_diff(*r);
+        result += distance
+    }
+
+    // Result
+    result
+}
+
+pub fn part2(left: &[usize], right_numbers: &[usize]) -> usize {
+    // Preamble
+    let mut right = HashMap::new();
+
+    for right_number in right_numbers {
+        let value = right.entry(*right_number).or_insert(0_usize);
+        value.add_assign(1);
+    }
+
+    // Solve
+    let mut result = 0;
+
+    for n in left {
+        let appearances = right.get(n).unwrap_or(&0);
+        let similarity_score = n * appearances;
+        result += similarity_score;
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day01;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day01>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day01>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
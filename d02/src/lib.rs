@@ -0,0 +1,148 @@
+use utils::error::{input_lines, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+#[derive(Eq, PartialEq)]
+enum Direction {
+    Ascending,
+    Descending,
+    Unset,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut reports = Vec::new();
+
+        for line in input_lines(input) {
+            let numbers_as_string = line.text.split_ascii_whitespace();
+            let report: Vec<i32> = numbers_as_string
+                .map(|n| line.parse(n))
+                .collect::<Result<_, _>>()?;
+            reports.push(report)
+        }
+
+        Ok(reports)
+    }
+
+    fn part1(reports: &Self::Input) -> Self::Answer1 {
+        part1(reports)
+    }
+
+    fn part2(reports: &Self::Input) -> Self::Answer2 {
+        part2(reports)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day02>)]
+}
+
+pub fn part1(reports: &[Vec<i32>]) -> usize {
+    // Solve
+    let mut result = 0;
+
+    'outer: for report in reports {
+        let mut direction = Direction::Unset;
+        for window in report.windows(2) {
+            let distance = window[0] - window[1];
+            let current_direction = match distance {
+                -3 => Direction::Ascending,
+                -2 => Direction::Ascending,
+                -1 => Direction::Ascending,
+                1 => Direction::Descending,
+                2 => Direction::Descending,
+                3 => Direction::Descending,
+                _ => continue 'outer,
+            };
+
+            if direction == Direction::Unset {
+                direction = current_direction;
+                continue;
+            }
+
+            if direction != current_direction {
+                continue 'outer;
+            }
+        }
+        result += 1;
+    }
+
+    // Result
+    result
+}
+
+pub fn part2(reports: &[Vec<i32>]) -> usize {
+    // Solve
+    let mut result = 0;
+
+    for report in reports {
+        let mut success = false;
+        'next: for skip in (0..report.len()).rev() {
+            let mut direction = Direction::Unset;
+            for i in 0..report.len() - 1 {
+                let current_i = if i == skip { i + 1 } else { i };
+                let mut next = current_i + 1;
+                if next == skip {
+                    next += 1;
+                }
+                if next >= report.len() {
+                    break;
+                }
+                let distance = report[current_i] - report[next];
+                let current_direction = match distance {
+                    -3 => Direction::Ascending,
+                    -2 => Direction::Ascending,
+                    -1 => Direction::Ascending,
+                    1 => Direction::Descending,
+                    2 => Direction::Descending,
+                    3 => Direction::Descending,
+                    _ => {
+                        // println!("Next at position {} {}",report[current_i], report[next]);
+                        continue 'next;
+                    }
+                };
+
+                if direction == Direction::Unset {
+                    direction = current_direction;
+                    continue;
+                }
+
+                if direction != current_direction {
+                    continue 'next;
+                }
+            }
+            success = true;
+            break;
+        }
+        if success {
+            // println!("Report  \"{:?}\" is ok", report);
+            result += 1;
+        }
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day02;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day02>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day02>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use regex::Regex;
+use utils::error::AocError;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(line: &Self::Input) -> Self::Answer1 {
+        part1(line)
+    }
+
+    fn part2(line: &Self::Input) -> Self::Answer2 {
+        part2(line)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day03>)]
+}
+
+pub fn part1(line: &str) -> usize {
+    // Preamble
+    let mut result: usize = 0;
+    let rgx = Regex::new(r#"mul\((\d+),(\d+)\)"#).unwrap();
+
+    // Parse
+    let capture_matches = rgx.captures_iter(line);
+
+    // Solve
+    for matches in capture_matches {
+        let left: usize = matches.get(1).unwrap().as_str().parse().unwrap();
+        let right: usize = matches.get(2).unwrap().as_str().parse().unwrap();
+        // println!("Found mul {left}, {right}");
+        result += left * right
+    }
+
+    // Result
+    result
+}
+
+pub fn part2(line: &str) -> usize {
+    // Preamble
+    let mut result: usize = 0;
+    let rgx = Regex::new(r#"mul\((\d+),(\d+)\)|don\'t\(\)|do\(\)"#).unwrap();
+
+    // Parse
+    let capture_matches = rgx.captures_iter(line);
+
+    // Solve
+    let mut is_active = true;
+    for matches in capture_matches {
+        let full_text = matches.get(0).unwrap().as_str();
+        match full_text {
+            "do()" => {
+                is_active = true;
+            }
+            "don't()" => {
+                is_active = false;
+            }
+            _ => {
+                if is_active {
+                    let left: usize = matches.get(1).unwrap().as_str().parse().unwrap();
+                    let right: usize = matches.get(2).unwrap().as_str().parse().unwrap();
+                    // println!("Found mul {left}, {right}");
+                    result += left * right
+                }
+            }
+        }
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day03;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day03>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day03>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+}
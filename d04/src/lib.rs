@@ -0,0 +1,104 @@
+use utils::error::AocError;
+use utils::grid::{Grid, GridView};
+use utils::grid_direction::Grid8WayDirection;
+use utils::grid_point::GridPoint;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day04>)]
+}
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    // Preamble
+    const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+
+    // Solve
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+    let mut result = 0;
+
+    for x in 0..max_x {
+        'next_y: for y in 0..max_y {
+            let start = GridPoint { x, y };
+            'next_direction: for direction in Grid8WayDirection::ALL {
+                if grid.get_from_point(&start).is_none_or(|&c| c != XMAS[0]) {
+                    continue 'next_y;
+                }
+                let mut current_position = start;
+                for expected in XMAS.iter().skip(1) {
+                    current_position = match current_position
+                        .next_by_8direction_with_check(&direction, max_x, max_y)
+                    {
+                        None => continue 'next_direction,
+                        Some(pos) => pos,
+                    };
+                    if grid
+                        .get_from_point(&current_position)
+                        .is_none_or(|c| c != expected)
+                    {
+                        continue 'next_direction;
+                    }
+                    // println!("Current position: x: {} y: {} Current Direction: {}", current_position.x, current_position.y, direction);
+                }
+                result += 1;
+            }
+        }
+    }
+
+    // Result
+    result
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    // Solve
+    let result = grid.windows(3, 3).filter(is_x_mas).count();
+
+    // Result
+    result
+}
+
+/// Checks for an `A` in the center with `MAS` running through it on both diagonals.
+fn is_x_mas(window: &GridView<char>) -> bool {
+    let is_mas = |a: char, b: char| matches!((a, b), ('M', 'S') | ('S', 'M'));
+    window[GridPoint::new(1, 1)] == 'A'
+        && is_mas(window[GridPoint::new(0, 0)], window[GridPoint::new(2, 2)])
+        && is_mas(window[GridPoint::new(2, 0)], window[GridPoint::new(0, 2)])
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day04;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day04>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day04>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::Div;
+
+use utils::error::AocError;
+use utils::parse::{list, pair, split_sections};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct PrintQueue {
+    pub before_after: BTreeMap<usize, Vec<usize>>,
+    pub after_before: BTreeMap<usize, Vec<usize>>,
+    pub printings: Vec<Vec<usize>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PrintQueue;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut printings: Vec<Vec<usize>> = Vec::new();
+        let mut before_after: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut after_before: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+
+        let [rules, updates] = split_sections(input, ["page ordering rules", "updates"])?;
+
+        for line in &rules {
+            let (before, after) = pair(line, "|")?;
+            before_after.entry(before).or_default().push(after);
+            after_before.entry(after).or_default().push(before);
+        }
+
+        for line in &updates {
+            printings.push(list(line, line.text.trim(), ",")?);
+        }
+
+        Ok(PrintQueue {
+            before_after,
+            after_before,
+            printings,
+        })
+    }
+
+    fn part1(print_queue: &Self::Input) -> Self::Answer1 {
+        part1(print_queue)
+    }
+
+    fn part2(print_queue: &Self::Input) -> Self::Answer2 {
+        part2(print_queue)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day05>)]
+}
+
+pub fn part1(print_queue: &PrintQueue) -> usize {
+    // Preamble
+    let before_after = &print_queue.before_after;
+    let after_before = &print_queue.after_before;
+
+    // Solve
+    let mut result = 0;
+    // let mut successes = 0;
+    for printing in &print_queue.printings {
+        if validate_ordering(printing, before_after, after_before) {
+            let middle = printing.len().div(2);
+            // println!("Row {:?}", printing);
+            // println!("Middle: {middle} - {}", printing[middle]);
+            result += printing[middle];
+            // successes += 1;
+        }
+    }
+
+    // Result
+    // println!("Successes {}", successes);
+    result
+}
+
+pub fn part2(print_queue: &PrintQueue) -> usize {
+    // Preamble
+    let before_after = &print_queue.before_after;
+    let after_before = &print_queue.after_before;
+
+    // Solve
+    let mut result = 0;
+    for printing in &print_queue.printings {
+        if !validate_ordering(printing, before_after, after_before) {
+            // print!("From: {:?}", printing);
+            let printing = order_printing(printing.clone(), before_after);
+            // println!(" To: {:?}", printing);
+            let middle = printing.len().div(2);
+            result += printing[middle];
+        }
+    }
+
+    // Result
+    result
+}
+
+fn order_printing(
+    mut printing: Vec<usize>,
+    before_after: &BTreeMap<usize, Vec<usize>>,
+    // after_before: &HashMap<usize, Vec<usize>>,
+) -> Vec<usize> {
+    printing.sort_unstable_by(|l, r| {
+        if let Some(after) = before_after.get(l) {
+            if after.contains(r) {
+                return Ordering::Less;
+            }
+        }
+        // if let Some(before) = after_before.get(r) {
+        //     if before.contains(l) {
+        //         return Ordering::Greater;
+        //     }
+        // }
+        Ordering::Greater
+    });
+
+    printing
+}
+
+fn validate_ordering(
+    printing: &[usize],
+    before_after: &BTreeMap<usize, Vec<usize>>,
+    after_before: &BTreeMap<usize, Vec<usize>>,
+) -> bool {
+    for i in 0..printing.len() {
+        let current = printing[i];
+
+        // Before
+        let left = &printing[0..i];
+        if let Some(after) = before_after.get(&current) {
+            for a in after {
+                if left.contains(a) {
+                    return false;
+                }
+            }
+        }
+
+        // After
+        let right = &printing[i + 1..printing.len()];
+        if let Some(before) = after_before.get(&current) {
+            for b in before {
+                if right.contains(b) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day05;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day05>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day05>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
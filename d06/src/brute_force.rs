@@ -0,0 +1,50 @@
+use rayon::prelude::*;
+
+use utils::error::AocError;
+use utils::grid::Grid;
+use utils::grid_direction::GridDirection;
+use utils::solution::Solution;
+
+use crate::{has_cycle, part1, Day06};
+
+/// Tries an obstacle on every free cell of the grid, not only on the cells the guard walks over.
+pub struct Day06BruteForce;
+
+impl Solution for Day06BruteForce {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day06::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    // Preamble
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+    let guard = grid.find_first(&'^').expect("parse checks for the guard");
+    let guard_direction = GridDirection::Up;
+
+    // Solve
+    let result = grid
+        .iter_points()
+        .filter(|(_, value)| **value == '.')
+        .map(|(point, _)| point)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .filter(|obstacle| has_cycle(grid, guard, guard_direction, *obstacle, max_x, max_y))
+        .count();
+
+    // Result
+    result
+}
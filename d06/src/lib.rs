@@ -0,0 +1,170 @@
+use fxhash::{FxHashMap, FxHashSet};
+use rayon::prelude::*;
+use utils::direction::DirectionSet;
+use utils::error::AocError;
+use utils::grid::Grid;
+use utils::grid_direction::GridDirection;
+use utils::grid_point::GridPoint;
+use utils::parse::{require_chars, require_markers};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod brute_force;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        require_chars(input, &['.', '#', '^'])?;
+        let grid = input.parse()?;
+        require_markers(input, &grid, &['^'])?;
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day06>),
+        ("brute_force", solve_timed::<brute_force::Day06BruteForce>),
+    ]
+}
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    // Preamble
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+    let guard = grid.find_first(&'^').expect("parse checks for the guard");
+    let guard_direction = GridDirection::Up;
+
+    // Solve
+    let result = create_x_points(grid, guard, guard_direction, max_x, max_y).len();
+
+    // Result
+    result
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    // Preamble
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+    let guard = grid.find_first(&'^').expect("parse checks for the guard");
+    let guard_direction = GridDirection::Up;
+
+    let points = create_x_points(grid, guard, guard_direction, max_x, max_y);
+
+    // Solve
+    let result: usize = points
+        .into_par_iter()
+        .filter(|obstacle| {
+            obstacle != &guard && has_cycle(grid, guard, guard_direction, *obstacle, max_x, max_y)
+        })
+        .count();
+
+    // Result
+    result
+}
+
+fn create_x_points(
+    grid: &Grid<char>,
+    mut guard: GridPoint,
+    mut guard_direction: GridDirection,
+    max_x: usize,
+    max_y: usize,
+) -> Vec<GridPoint> {
+    let mut rtn: FxHashSet<GridPoint> = FxHashSet::default();
+    loop {
+        let _ = rtn.insert(guard);
+
+        let next = match guard.next_by_direction_with_check(&guard_direction, max_x, max_y) {
+            None => break,
+            Some(value) => value,
+        };
+
+        let next_value = match grid.get_from_point(&next) {
+            None => break,
+            Some(value) => value,
+        };
+
+        match next_value {
+            '#' | 'O' => guard_direction.turn_right(),
+            '.' | '^' => guard = next,
+            _ => panic!("Should not be here. Value is {next_value}"),
+        }
+    }
+
+    rtn.into_iter().collect()
+}
+
+pub(crate) fn has_cycle(
+    grid: &Grid<char>,
+    mut guard: GridPoint,
+    mut guard_direction: GridDirection,
+    obstacle: GridPoint,
+    max_x: usize,
+    max_y: usize,
+) -> bool {
+    let mut seen: FxHashMap<GridPoint, DirectionSet> = FxHashMap::default();
+    loop {
+        if !seen.entry(guard).or_default().insert(guard_direction) {
+            return true;
+        }
+
+        let next = match guard.next_by_direction_with_check(&guard_direction, max_x, max_y) {
+            None => return false,
+            Some(value) => value,
+        };
+
+        if next == obstacle {
+            guard_direction.turn_right();
+            continue;
+        }
+
+        let next_value = match grid.get_from_point(&next) {
+            None => return false,
+            Some(value) => value,
+        };
+
+        match next_value {
+            '#' | 'O' => guard_direction.turn_right(),
+            '.' | '^' => guard = next,
+            _ => panic!("Should not be here. Value is {next_value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day06};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day06>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day06>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+    }
+}
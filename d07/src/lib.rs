@@ -0,0 +1,246 @@
+use rayon::prelude::*;
+
+use utils::digits::concatenate;
+use utils::error::{input_lines, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod reverse;
+
+/// Most numbers an equation can have, the `3^(n - 1)` operator combinations of part 2 would not
+/// fit into a `usize` with more.
+const MAX_NUMBERS: usize = 41;
+
+#[derive(Debug)]
+pub struct Equation {
+    pub result: u64,
+    pub numbers: Vec<u64>,
+}
+
+impl Equation {
+    pub(crate) fn is_valid(&self) -> bool {
+        let max_combinations: u64 = 1 << (self.numbers.len() - 1);
+
+        'combinations: for mut i in 0..max_combinations {
+            let mut current = *self.numbers.first().unwrap();
+            for n in self.numbers.iter().skip(1) {
+                let operation = if (i & 1) == 0 {
+                    Operation::Add
+                } else {
+                    Operation::Multiply
+                };
+                i >>= 1;
+                let next = match operation {
+                    Operation::Add => current.checked_add(*n),
+                    Operation::Multiply => current.checked_mul(*n),
+                    Operation::Concatenate => panic!("Should not be here!"),
+                };
+                // A combination that overflows can never come back down to the result.
+                let Some(next) = next else {
+                    continue 'combinations;
+                };
+                current = next;
+            }
+            if current == self.result {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub(crate) fn is_valid_v2(&self) -> bool {
+        let max_combinations: usize = 3_usize.pow((self.numbers.len() - 1) as u32);
+
+        'combinations: for mut i in 0..max_combinations {
+            let mut current = *self.numbers.first().unwrap();
+            for n in self.numbers.iter().skip(1) {
+                let operation = match i % 3 {
+                    0 => Operation::Add,
+                    1 => Operation::Multiply,
+                    2 => Operation::Concatenate,
+                    _ => panic!("Should not be here!"),
+                };
+                i = i.checked_div(3).unwrap_or(0);
+                let next = match operation {
+                    Operation::Add => current.checked_add(*n),
+                    Operation::Multiply => current.checked_mul(*n),
+                    Operation::Concatenate => concatenate(current, *n),
+                };
+                // A combination that overflows can never come back down to the result.
+                let Some(next) = next else {
+                    continue 'combinations;
+                };
+                current = next;
+            }
+            if current == self.result {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // pub(crate) fn count_valid(&self) -> usize {
+    //     let mut rtn = 0;
+    //     let max_combinations = 1 << (self.numbers.len()-1);
+    //
+    //     for i in 0..max_combinations {
+    //         let mut current = *self.numbers.first().unwrap();
+    //         for (p, n) in self.numbers.iter().skip(1).enumerate() {
+    //             let operation = if (i & (2_usize.pow(p as u32))) == 0 {
+    //                Operation::Add
+    //             } else {
+    //                 Operation::Multiply
+    //             };
+    //             current = match operation {
+    //                 Operation::Add => {
+    //                     current + n
+    //                 }
+    //                 Operation::Multiply => {
+    //                     current * n
+    //                 }
+    //             }
+    //         }
+    //         if current == self.result{
+    //             rtn += 1;
+    //         }
+    //     }
+    //     rtn
+    // }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut equations: Vec<Equation> = Vec::new();
+
+        for line in input_lines(input) {
+            let mut split = line.text.split(":").map(|x| x.trim());
+            let result: u64 = line.parse_next(&mut split, "test value")?;
+            let numbers_text = line.next(&mut split, "numbers after :")?;
+            let numbers: Vec<u64> = numbers_text
+                .split_ascii_whitespace()
+                .map(|x| line.parse(x))
+                .collect::<Result<_, _>>()?;
+            if !(1..=MAX_NUMBERS).contains(&numbers.len()) {
+                return Err(
+                    line.error(numbers_text, format!("expected 1 to {MAX_NUMBERS} numbers"))
+                );
+            }
+
+            equations.push(Equation { result, numbers });
+        }
+
+        Ok(equations)
+    }
+
+    fn part1(equations: &Self::Input) -> Self::Answer1 {
+        part1(equations)
+    }
+
+    fn part2(equations: &Self::Input) -> Self::Answer2 {
+        part2(equations)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day07>),
+        ("reverse", solve_timed::<reverse::Day07Reverse>),
+    ]
+}
+
+pub fn part1(equations: &[Equation]) -> u64 {
+    // Solve
+    // let mut result = 0;
+    // let max = equations.len();
+
+    // for (i, equation) in equations.iter().enumerate() {
+    // for equation in equations.iter(){
+    //     // println!("Equation {i} has {} valid results.", equation.count_valid());
+    //     if equation.is_valid() {
+    //         result += equation.result;
+    //     }
+    // }
+
+    let result: u64 = equations
+        .par_iter()
+        .filter_map(|equation| {
+            if equation.is_valid() {
+                Some(equation.result)
+            } else {
+                None
+            }
+        })
+        .sum();
+
+    // Result
+    result
+}
+
+pub fn part2(equations: &[Equation]) -> u64 {
+    // Solve
+    // let mut result = 0;
+    // // let max = equations.len();
+    //
+    // // for (i, equation) in equations.iter().enumerate() {
+    // for equation in equations.iter(){
+    //     // println!("Equation {i} has {} valid results.", equation.count_valid());
+    //     // println!("Handling Equation {} of {max}", i + 1);
+    //     if equation.is_valid() || equation.is_valid_v2() {
+    //         result += equation.result;
+    //     }
+    // }
+
+    let result: u64 = equations
+        .par_iter()
+        .filter_map(|equation| {
+            if equation.is_valid_v2() {
+                Some(equation.result)
+            } else {
+                None
+            }
+        })
+        .sum();
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day07};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day07>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day07>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+    }
+}
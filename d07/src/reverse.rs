@@ -0,0 +1,59 @@
+use rayon::prelude::*;
+
+use utils::digits::deconcatenate;
+use utils::error::AocError;
+use utils::solution::Solution;
+
+use crate::{Day07, Equation};
+
+/// Works backwards from the test value instead of trying every combination of operators. The
+/// last number has to be undone by the last operator, so subtraction, division and cutting off
+/// its digits only continue where they leave a whole number, which prunes most branches early.
+pub struct Day07Reverse;
+
+impl Solution for Day07Reverse {
+    type Input = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day07::parse(input)
+    }
+
+    fn part1(equations: &Self::Input) -> Self::Answer1 {
+        sum_valid(equations, false)
+    }
+
+    fn part2(equations: &Self::Input) -> Self::Answer2 {
+        sum_valid(equations, true)
+    }
+}
+
+fn sum_valid(equations: &[Equation], concatenate: bool) -> u64 {
+    // Solve
+    let result = equations
+        .par_iter()
+        .filter(|equation| can_produce(equation.result, &equation.numbers, concatenate))
+        .map(|equation| equation.result)
+        .sum();
+
+    // Result
+    result
+}
+
+fn can_produce(target: u64, numbers: &[u64], concatenate: bool) -> bool {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return last == target;
+    }
+
+    (target >= last && can_produce(target - last, rest, concatenate))
+        || (last != 0
+            && target.is_multiple_of(last)
+            && can_produce(target / last, rest, concatenate))
+        || (concatenate
+            && deconcatenate(target, last)
+                .is_some_and(|prefix| can_produce(prefix, rest, concatenate)))
+}
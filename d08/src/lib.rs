@@ -0,0 +1,130 @@
+use std::collections::{HashMap, HashSet};
+use utils::error::AocError;
+use utils::point::MapPoint;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct AntennaMap {
+    pub data: HashMap<char, Vec<MapPoint>>,
+    pub max_x: usize,
+    pub max_y: usize,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = AntennaMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut data: HashMap<char, Vec<MapPoint>> = HashMap::new();
+
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let line = line.trim();
+            max_x = max_x.max(line.len());
+            for (x, c) in line.chars().enumerate() {
+                if c != '.' {
+                    data.entry(c)
+                        .or_default()
+                        .push(MapPoint::new(x as i64, y as i64));
+                }
+            }
+            max_y = y;
+        }
+        max_y += 1;
+
+        Ok(AntennaMap { data, max_x, max_y })
+    }
+
+    fn part1(antenna_map: &Self::Input) -> Self::Answer1 {
+        part1(antenna_map)
+    }
+
+    fn part2(antenna_map: &Self::Input) -> Self::Answer2 {
+        part2(antenna_map)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day08>)]
+}
+
+pub fn part1(antenna_map: &AntennaMap) -> usize {
+    // Preamble
+    let AntennaMap { data, max_x, max_y } = antenna_map;
+
+    let mut antinodes = HashSet::new();
+
+    // Solve
+    for de in data.iter() {
+        for i1 in 0..de.1.len() {
+            for i2 in i1 + 1..de.1.len() {
+                let e1 = de.1[i1];
+                let e2 = de.1[i2];
+                let delta = e2 - e1;
+                for antinode in [e1 - delta, e2 + delta] {
+                    if is_on_map(&antinode, *max_x, *max_y) {
+                        let _ = antinodes.insert(antinode);
+                    }
+                }
+            }
+        }
+    }
+
+    // Result
+    antinodes.len()
+}
+
+pub fn part2(antenna_map: &AntennaMap) -> usize {
+    // Preamble
+    let AntennaMap { data, max_x, max_y } = antenna_map;
+
+    let mut antinodes = HashSet::new();
+
+    // Solve
+    for de in data.iter() {
+        for i1 in 0..de.1.len() {
+            for i2 in i1 + 1..de.1.len() {
+                let e1 = de.1[i1];
+                let e2 = de.1[i2];
+                let delta = e2 - e1;
+                for (mut current, step) in [(e1, -delta), (e2, delta)] {
+                    while is_on_map(&current, *max_x, *max_y) {
+                        let _ = antinodes.insert(current);
+                        current += step;
+                    }
+                }
+            }
+        }
+    }
+
+    // Result
+    antinodes.len()
+}
+
+fn is_on_map(point: &MapPoint, max_x: usize, max_y: usize) -> bool {
+    point
+        .try_cast::<usize>()
+        .is_some_and(|p| p.x < max_x && p.y < max_y)
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day08;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day08>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day08>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,200 @@
+use utils::error::{input_lines, unexpected_end, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u8>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let line = input_lines(input)
+            .next()
+            .filter(|line| !line.text.trim().is_empty())
+            .ok_or_else(|| unexpected_end(input, "disk map"))?;
+        let text = line.text.trim();
+        let start = line.position_of(text).column;
+
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or(AocError::UnexpectedChar {
+                        found: c,
+                        position: Some(line.position(start + i)),
+                    })
+            })
+            .collect()
+    }
+
+    fn part1(disk_map: &Self::Input) -> Self::Answer1 {
+        part1(disk_map)
+    }
+
+    fn part2(disk_map: &Self::Input) -> Self::Answer2 {
+        part2(disk_map)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day09>)]
+}
+
+pub fn part1(disk_map: &[u8]) -> u64 {
+    // Preamble
+    const EMPTY_SPACE: u16 = 0xFFFF;
+
+    // Prepare
+    let mut disk: Vec<u16> = Vec::with_capacity(disk_map.len() * 5);
+
+    // Solve
+    let mut file_id = 0;
+    let mut is_file = true;
+    for &number in disk_map {
+        if is_file {
+            (0..number).for_each(|_| disk.push(file_id));
+            file_id += 1;
+        } else {
+            (0..number).for_each(|_| disk.push(EMPTY_SPACE));
+        }
+
+        is_file = !is_file;
+    }
+
+    let mut last_position = disk.len() - 1;
+    for pos in 0..disk.len() {
+        if pos >= last_position {
+            break;
+        }
+        if disk[pos] == EMPTY_SPACE {
+            disk.swap(pos, last_position);
+            loop {
+                last_position -= 1;
+                if disk[last_position] != EMPTY_SPACE {
+                    break;
+                }
+            }
+        }
+    }
+    let mut result: u64 = 0;
+    for (i, disk_space) in disk.iter().enumerate() {
+        if *disk_space == EMPTY_SPACE {
+            break;
+        }
+
+        result += (i * (*disk_space) as usize) as u64;
+    }
+
+    // Result
+    result
+}
+
+#[derive(Debug, Copy, Clone)]
+struct DiskSpace {
+    file_id: Option<u16>,
+    size: u16,
+}
+
+pub fn part2(disk_map: &[u8]) -> u64 {
+    // Prepare
+    let mut disk: Vec<DiskSpace> = Vec::with_capacity(disk_map.len() * 2);
+
+    // Solve
+    let mut file_id = 0;
+    let mut is_file = true;
+    for &number in disk_map {
+        if is_file {
+            disk.push(DiskSpace {
+                file_id: Some(file_id),
+                size: number as u16,
+            });
+            file_id += 1;
+        } else {
+            disk.push(DiskSpace {
+                file_id: None,
+                size: number as u16,
+            });
+        }
+
+        is_file = !is_file;
+    }
+
+    let mut last_file_position = disk.len();
+    for file_id in (0..file_id).rev() {
+        let (file_position, _) = disk[0..last_file_position]
+            .iter()
+            .enumerate()
+            .rfind(|(_file_position, f)| f.file_id.is_some_and(|fid| fid == file_id))
+            .expect("Could not find the disk part. This should not happen");
+
+        last_file_position = file_position;
+
+        let free_space: Option<usize> =
+            disk[0..file_position]
+                .iter()
+                .enumerate()
+                .find_map(|(i, ds)| {
+                    if ds.file_id.is_none() && ds.size >= disk[file_position].size {
+                        Some(i)
+                    } else {
+                        None
+                    }
+                });
+
+        if let Some(free_space_position) = free_space {
+            if disk[file_position].size == disk[free_space_position].size {
+                disk.swap(file_position, free_space_position);
+                // disk.remove(file_position);
+            } else {
+                disk[free_space_position].size -= disk[file_position].size;
+                let file = disk[file_position];
+                disk[file_position].file_id = None;
+                disk.insert(free_space_position, file);
+            }
+        }
+    }
+
+    // for ds in disk {
+    //     for _ in 0..ds.size {
+    //         if let Some(d) = ds.file_id {
+    //             print!("{}", d);
+    //         } else {
+    //             print!(".");
+    //         }
+    //     }
+    // }
+
+    let mut result: u64 = 0;
+    let mut positions = 0;
+    for ds in disk {
+        for _ in 0..ds.size {
+            if let Some(d) = ds.file_id {
+                result += positions as u64 * d as u64;
+            }
+            positions += 1;
+        }
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day09;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day09>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day09>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
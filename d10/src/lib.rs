@@ -0,0 +1,86 @@
+use utils::error::AocError;
+use utils::grid::{Adjacency, Grid};
+use utils::grid_point::GridPoint;
+use utils::search::SearchResult;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse_with(input, |c| c.to_digit(10).map_or(0xFF, |d| d as u8))
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day10>)]
+}
+
+pub fn part1(grid: &Grid<u8>) -> usize {
+    // Solve
+    let result = trailheads(grid)
+        .map(|trails| {
+            trails
+                .distances()
+                .filter(|(position, _)| grid[**position] == 9)
+                .count()
+        })
+        .sum();
+
+    // Result
+    result
+}
+
+pub fn part2(grid: &Grid<u8>) -> usize {
+    // Solve
+    let result = trailheads(grid)
+        .map(|trails| {
+            trails
+                .distances()
+                .filter(|(position, _)| grid[**position] == 9)
+                .map(|(position, _)| trails.path_count(position))
+                .sum::<usize>()
+        })
+        .sum();
+
+    // Result
+    result
+}
+
+/// All trails of every trailhead. A trail goes up by exactly one per step, so every trail to a
+/// summit is one of the shortest paths there.
+fn trailheads(grid: &Grid<u8>) -> impl Iterator<Item = SearchResult<GridPoint>> + '_ {
+    grid.iter_points()
+        .filter(|(_, &height)| height == 0)
+        .map(|(start, _)| grid.bfs_from(start, Adjacency::FourWay, |&from, &to| to == from + 1))
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day10;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day10>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day10>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,114 @@
+use fxhash::FxHashMap;
+use std::ops::AddAssign;
+use utils::digits::{digit_count, split_number};
+use utils::error::{input_lines, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input_lines(input)
+            .flat_map(|line| {
+                line.text
+                    .split_ascii_whitespace()
+                    .map(move |s| line.parse(s))
+            })
+            .collect()
+    }
+
+    fn part1(stones: &Self::Input) -> Self::Answer1 {
+        part1(stones)
+    }
+
+    fn part2(stones: &Self::Input) -> Self::Answer2 {
+        part2(stones)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day11>)]
+}
+
+pub fn part1(stones: &[u64]) -> usize {
+    // Preamble
+    const NUMBER_OF_BLINKS: usize = 25;
+    let mut stones = stones.to_vec();
+
+    for _ in 0..NUMBER_OF_BLINKS {
+        let mut next = Vec::with_capacity(stones.len() * 2);
+        for stone in stones {
+            let (next_stone, optional_next) = check_stone(stone);
+            next.push(next_stone);
+            if let Some(other_stone) = optional_next {
+                next.push(other_stone);
+            }
+        }
+        stones = next;
+    }
+
+    // Result
+    stones.len()
+}
+
+pub fn part2(stones: &[u64]) -> u64 {
+    // Preamble
+    const NUMBER_OF_BLINKS: usize = 75;
+
+    // Prepare
+    let mut context: FxHashMap<u64, u64> = FxHashMap::default();
+
+    for &stone in stones {
+        context.entry(stone).or_default().add_assign(1);
+    }
+
+    // Solve
+    for _ in 0..NUMBER_OF_BLINKS {
+        let mut next: FxHashMap<u64, u64> = FxHashMap::default();
+        for (stone, amount) in context {
+            let (next_stone, optional_next) = check_stone(stone);
+            next.entry(next_stone).or_default().add_assign(amount);
+            if let Some(other_stone) = optional_next {
+                next.entry(other_stone).or_default().add_assign(amount);
+            }
+        }
+        context = next;
+    }
+
+    // Result
+    context.values().sum()
+}
+
+fn check_stone(stone: u64) -> (u64, Option<u64>) {
+    if stone == 0 {
+        return (1, None);
+    }
+    let digits = digit_count(stone);
+    if digits.is_multiple_of(2) {
+        let (left, right) = split_number(stone, digits / 2);
+        return (left, Some(right));
+    }
+    (stone * 2024, None)
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day11;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day11>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day11>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
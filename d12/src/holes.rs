@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::error::AocError;
+use utils::grid::Grid;
+use utils::grid_direction::GridDirection;
+use utils::grid_point::GridPoint;
+use utils::regions::{find_regions, Region};
+use utils::solution::Solution;
+
+use crate::{part1, Day12};
+
+/// Counts the sides by walking the outline of every region and of every hole inside of it.
+pub struct Day12Holes;
+
+impl Solution for Day12Holes {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day12::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    find_regions(grid, |a, b| a == b)
+        .regions()
+        .iter()
+        .map(|region| region.area() * count_sides(region))
+        .sum()
+}
+
+fn count_sides(region: &Region) -> usize {
+    // Holes
+    let mut seen = HashMap::new();
+    let mut holes = Vec::new();
+    for y in region.min.y + 1..region.max.y {
+        for x in region.min.x + 1..region.max.x {
+            let position = GridPoint { x, y };
+            if !region.cells.contains(&position)
+                && !seen.contains_key(&position)
+                && is_hole(region, position, &mut seen)
+            {
+                // dbg!(&region);
+                // dbg!(&seen);
+                holes.push(position);
+            }
+        }
+    }
+
+    // println!("Region {} has {} holes", region.id, holes.len());
+
+    let mut points: HashMap<GridPoint, Vec<GridDirection>> = HashMap::new();
+    let max_x = region.max.x + 1;
+    let max_y = region.max.y + 1;
+
+    // Graph
+    for area in region.cells.iter() {
+        let tl = points.entry(*area).or_default();
+        tl.push(GridDirection::Right);
+        tl.push(GridDirection::Down);
+        let tr = points.entry(area.next_right()).or_default();
+        tr.push(GridDirection::Down);
+        tr.push(GridDirection::Left);
+        let dl = points.entry(area.next_down()).or_default();
+        dl.push(GridDirection::Up);
+        dl.push(GridDirection::Right);
+        let dr = points
+            .entry(GridPoint::new(area.x + 1, area.y + 1))
+            .or_default();
+        dr.push(GridDirection::Up);
+        dr.push(GridDirection::Left);
+    }
+
+    let start = *region.cells.first().unwrap();
+    holes.push(start);
+
+    // Find boarders
+
+    let mut rtn: usize = 0;
+    for s in holes {
+        rtn += get_boarders_count(s, &points, max_x, max_y)
+    }
+    // println!("Region {} has {} sides", region.id, rtn);
+    rtn
+}
+
+fn get_boarders_count(
+    start: GridPoint,
+    points: &HashMap<GridPoint, Vec<GridDirection>>,
+    max_x: usize,
+    max_y: usize,
+) -> usize {
+    let mut direction = GridDirection::Up;
+
+    let mut direction_list = Vec::with_capacity(points.len());
+    let mut points_list = Vec::with_capacity(points.len());
+
+    let mut position = start;
+    loop {
+        let current_point = points.get(&position).unwrap();
+        let next_list = match direction {
+            GridDirection::Up => [GridDirection::Left, GridDirection::Up, GridDirection::Right],
+            GridDirection::Right => [GridDirection::Up, GridDirection::Right, GridDirection::Down],
+            GridDirection::Down => [
+                GridDirection::Right,
+                GridDirection::Down,
+                GridDirection::Left,
+            ],
+            GridDirection::Left => [GridDirection::Down, GridDirection::Left, GridDirection::Up],
+        };
+
+        // points_list.push(position);
+        // direction_list.push(direction);
+
+        for next in next_list.iter().filter(|&p| current_point.contains(p)) {
+            if let Some(next_position) = position.next_by_direction_with_check(next, max_x, max_y) {
+                // if let Some(point) = points.get(&next_position) {
+                direction_list.push(*next);
+                points_list.push(next_position);
+                direction = *next;
+                position = next_position;
+                break;
+                // }
+            }
+        }
+
+        if position == start {
+            break;
+        }
+    }
+
+    // dbg!(points_list);
+    // dbg!(&direction_list);
+
+    let mut rtn = 1;
+    for window in direction_list.windows(2) {
+        if window[0] != window[1] {
+            rtn += 1;
+        }
+    }
+
+    rtn
+}
+
+fn is_hole(region: &Region, start: GridPoint, seen: &mut HashMap<GridPoint, bool>) -> bool {
+    let mut queue = vec![start];
+
+    let min_x = region.min.x;
+    let min_y = region.min.y;
+    let max_x = region.max.x;
+    let max_y = region.max.y;
+
+    let mut inner_seen = HashSet::new();
+
+    let mut rtn = true;
+
+    while let Some(item) = queue.pop() {
+        if let Some(rtn) = seen.get(&item) {
+            return *rtn;
+        }
+
+        if !inner_seen.insert(item) {
+            continue;
+        }
+        if item.x <= min_x || item.x >= max_x || item.y <= min_y || item.y >= max_y {
+            rtn = false;
+        }
+
+        for neighbor in item.generate_non_diagonal_neighbors_with_check(max_x + 2, max_y + 2) {
+            if !region.cells.contains(&neighbor) {
+                queue.push(neighbor);
+            }
+        }
+    }
+
+    inner_seen.into_iter().for_each(|t| {
+        seen.insert(t, rtn);
+    });
+
+    rtn
+}
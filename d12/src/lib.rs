@@ -0,0 +1,85 @@
+use utils::error::AocError;
+use utils::grid::Grid;
+use utils::regions::find_regions;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod holes;
+pub mod vertices;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day12>),
+        ("holes", solve_timed::<holes::Day12Holes>),
+        ("vertices", solve_timed::<vertices::Day12Vertices>),
+    ]
+}
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    // Solve
+    let result = find_regions(grid, |a, b| a == b)
+        .regions()
+        .iter()
+        .map(|region| region.area() * region.perimeter())
+        .sum();
+
+    // Result
+    result
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    // Solve
+    let result = find_regions(grid, |a, b| a == b)
+        .regions()
+        .iter()
+        .map(|region| region.area() * region.sides())
+        .sum();
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day12};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day12>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day12>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+    }
+}
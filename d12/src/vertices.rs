@@ -1,90 +1,33 @@
 use std::cell::RefCell;
 use std::collections::HashSet;
-use std::ops::AddAssign;
-use std::path::Path;
 use std::rc::Rc;
+
+use utils::error::AocError;
 use utils::grid::Grid;
 use utils::grid_point::GridPoint;
-use utils::{get_input_path, parse_into_char_vector_vector};
-
-#[derive(Debug)]
-#[allow(dead_code)]
-struct Region {
-    name: char,
-    area: usize,
-    fences: usize,
-}
-
-#[derive(Debug)]
-#[allow(dead_code)]
-struct Region2 {
-    name: char,
-    area: Vec<GridPoint>,
-    fences: usize,
-    min_x: usize,
-    max_x: usize,
-    min_y: usize,
-    max_y: usize,
-}
+use utils::solution::Solution;
 
-fn run(input_file: &Path) {
-    // Preamble
+use crate::{part1, Day12};
 
-    // Parse
-    let grid: Grid<char> = parse_into_char_vector_vector(input_file).into();
-    let max_x = grid.get_max_x();
-    let max_y = grid.get_max_y();
+/// Counts the sides of a region as its number of corners, found while flood filling it.
+pub struct Day12Vertices;
 
-    let mut regions = Vec::new();
-    let mut region_grid: Grid<Option<Rc<RefCell<Region>>>> =
-        Grid::with_width_height(max_x, max_y, None);
+impl Solution for Day12Vertices {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    // Prepare
-    for y in 0..max_y {
-        for x in 0..max_x {
-            if region_grid.data[y][x].is_some() {
-                continue;
-            }
-
-            let name = grid.data[y][x];
-
-            let mut queue = Vec::new();
-            queue.push(GridPoint { x, y });
-
-            let current_region = Rc::new(RefCell::new(Region {
-                name,
-                area: 0,
-                fences: 0,
-            }));
-
-            regions.push(current_region.clone());
-            let mut current_region_cell = current_region.borrow_mut();
-
-            while let Some(position) = queue.pop() {
-                if region_grid.data[position.y][position.x].is_some() {
-                    continue;
-                }
-
-                let (fences, neighbors) =
-                    get_fences_and_neighbors(position, name, &grid, max_x, max_y);
-
-                current_region_cell.area.add_assign(1);
-                current_region_cell.fences.add_assign(fences);
-
-                neighbors.into_iter().for_each(|i| queue.push(i));
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day12::parse(input)
+    }
 
-                region_grid.set_from_point(&position, Some(current_region.clone()));
-            }
-        }
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
     }
 
-    // Solve
-    let mut result = 0;
-    for region in regions {
-        result += region.borrow().area * region.borrow().fences;
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
     }
-    // Result
-    println!("Result of part 1 is {}", result);
 }
 
 #[derive(Debug, Default)]
@@ -94,14 +37,11 @@ struct RegionCounter {
     vertex_count: usize,
 }
 
-fn run2(input_file: &Path) {
+fn part2(grid: &Grid<char>) -> usize {
     // Preamble
     let mut visited: HashSet<GridPoint> = HashSet::new();
     // let mut total_borders = 0;
     let mut total_vertices = 0;
-
-    // Parse
-    let grid: Grid<char> = parse_into_char_vector_vector(input_file).into();
     let max_x = grid.get_max_x();
     let max_y = grid.get_max_y();
 
@@ -113,7 +53,7 @@ fn run2(input_file: &Path) {
                     position,
                     &mut visited,
                     Rc::new(RefCell::new(RegionCounter::default())),
-                    &grid,
+                    grid,
                     max_x,
                     max_y,
                 );
@@ -125,7 +65,8 @@ fn run2(input_file: &Path) {
         }
     }
 
-    println!("Result of part 2 is {}", total_vertices);
+    // Result
+    total_vertices
 }
 
 fn traverse_region(
@@ -141,11 +82,12 @@ fn traverse_region(
     }
     counter.borrow_mut().visited_points.insert(position);
 
-    let value = grid.data[position.y][position.x];
+    let value = grid[position];
     let neighbours = position.generate_non_diagonal_neighbors_with_check(max_x, max_y);
     let reachable: Vec<GridPoint> = neighbours
         .iter()
-        .filter(|n| grid.data[n.y][n.x] == value).copied()
+        .filter(|n| grid[**n] == value)
+        .copied()
         .collect();
     let borders = 4 - reachable.len();
 
@@ -223,47 +165,3 @@ fn calculate_vertex_count(
 
     concave + convex
 }
-
-fn main() {
-    let input_file = get_input_path(env!("CARGO_MANIFEST_DIR"));
-
-    println!("Running {}", env!("CARGO_PKG_NAME"));
-    println!("InputFile: {}", input_file.display());
-
-    run(input_file.as_path());
-    run2(input_file.as_path());
-}
-
-fn get_fences_and_neighbors(
-    position: GridPoint,
-    name: char,
-    grid: &Grid<char>,
-    max_x: usize,
-    max_y: usize,
-) -> (usize, Vec<GridPoint>) {
-    let mut neighbors = Vec::with_capacity(4);
-    for neighbor in position.generate_non_diagonal_neighbors_with_check(max_x, max_y) {
-        if name == grid.data[neighbor.y][neighbor.x] {
-            neighbors.push(neighbor)
-        }
-    }
-    (4 - neighbors.len(), neighbors)
-}
-
-#[cfg(test)]
-mod main_test {
-    use utils::{get_test_input_2_path, get_test_input_path};
-
-    use crate::run;
-    use crate::run2;
-
-    #[test]
-    fn test_input_part_1() {
-        run(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-    }
-
-    #[test]
-    fn test_input_part_2() {
-        run2(&get_test_input_2_path(env!("CARGO_MANIFEST_DIR")));
-    }
-}
@@ -0,0 +1,132 @@
+use utils::error::{unexpected_end, AocError, InputLine};
+use utils::math::solve_2x2;
+use utils::parse::{integers_exact, sections};
+use utils::solution::{solve_timed, Solution, Variant};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Game {
+    pub button_a_x: usize,
+    pub button_a_y: usize,
+    pub button_b_x: usize,
+    pub button_b_y: usize,
+    pub prize_x: usize,
+    pub prize_y: usize,
+}
+
+// #[derive(Debug,Default)]
+// struct SearchContext {
+//     a_press:usize,
+//     b_press:usize,
+//     cost: usize
+// }
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        sections(input)
+            .iter()
+            .map(|lines| parse_game(input, lines))
+            .collect()
+    }
+
+    fn part1(games: &Self::Input) -> Self::Answer1 {
+        part1(games)
+    }
+
+    fn part2(games: &Self::Input) -> Self::Answer2 {
+        part2(games)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day13>)]
+}
+
+pub fn part1(games: &[Game]) -> usize {
+    // Solve
+    let result = games
+        .iter()
+        .filter_map(presses)
+        .filter(|&(a, b)| a < 100 && b < 100)
+        .map(|(a, b)| 3 * a + b)
+        .sum();
+
+    // Result
+    result
+}
+
+pub fn part2(games: &[Game]) -> usize {
+    // Preamble
+    let games = games.iter().map(|game| Game {
+        prize_x: game.prize_x + 10000000000000,
+        prize_y: game.prize_y + 10000000000000,
+        ..*game
+    });
+
+    // Solve
+    let result = games
+        .filter_map(|game| presses(&game))
+        .map(|(a, b)| 3 * a + b)
+        .sum();
+
+    // Result
+    result
+}
+
+/// Presses of button A and B that reach the prize, `None` if no whole, non-negative number of
+/// presses does.
+fn presses(game: &Game) -> Option<(usize, usize)> {
+    let [a, b] = solve_2x2(
+        [
+            [game.button_a_x as i64, game.button_b_x as i64],
+            [game.button_a_y as i64, game.button_b_y as i64],
+        ],
+        [game.prize_x as i64, game.prize_y as i64],
+    )?;
+    let a = usize::try_from(a.to_integer()?).ok()?;
+    let b = usize::try_from(b.to_integer()?).ok()?;
+    Some((a, b))
+}
+
+fn parse_game(input: &str, lines: &[InputLine]) -> Result<Game, AocError> {
+    let mut lines = lines.iter();
+    let mut next_coordinates = |what: &str| -> Result<[usize; 2], AocError> {
+        let line = lines.next().ok_or_else(|| unexpected_end(input, what))?;
+        integers_exact(line)
+    };
+
+    let [button_a_x, button_a_y] = next_coordinates("button A")?;
+    let [button_b_x, button_b_y] = next_coordinates("button B")?;
+    let [prize_x, prize_y] = next_coordinates("prize")?;
+    Ok(Game {
+        button_a_x,
+        button_a_y,
+        button_b_x,
+        button_b_y,
+        prize_x,
+        prize_y,
+    })
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day13;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day13>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day13>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,73 @@
+use utils::error::AocError;
+use utils::math::crt;
+use utils::solution::{MaybeAnswer, Parameters, Solution};
+
+use crate::{part1, Day14, Lobby};
+
+/// Finds the tree without looking at every second. The x coordinates repeat every `width`
+/// seconds and the y coordinates every `height` seconds, so the second where the robots are
+/// bunched up the most is searched for each axis on its own and both are combined with the
+/// Chinese Remainder Theorem.
+pub struct Day14Crt;
+
+impl Solution for Day14Crt {
+    type Input = Lobby;
+    type Answer1 = usize;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day14::parse(input)
+    }
+
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, AocError> {
+        Day14::parse_with(input, parameters)
+    }
+
+    fn part1(lobby: &Self::Input) -> Self::Answer1 {
+        part1(lobby)
+    }
+
+    fn part2(lobby: &Self::Input) -> Self::Answer2 {
+        MaybeAnswer(part2(lobby))
+    }
+}
+
+/// `None` if the seconds of both axes never line up, which needs a space whose width and height
+/// share a factor.
+fn part2(lobby: &Lobby) -> Option<usize> {
+    // Preamble
+    let (robots, width, height) = (&lobby.robots, lobby.width, lobby.height);
+
+    // Solve
+    let second_x = most_clustered(width, |second| {
+        robots
+            .iter()
+            .map(|robot| (robot.position_x + second * robot.velocity_x).rem_euclid(width))
+            .collect()
+    });
+    let second_y = most_clustered(height, |second| {
+        robots
+            .iter()
+            .map(|robot| (robot.position_y + second * robot.velocity_y).rem_euclid(height))
+            .collect()
+    });
+    let (result, _) = crt(&[(second_x, width), (second_y, height)])?;
+
+    // Result
+    Some(result as usize)
+}
+
+/// The second in `0..period` where the coordinates are spread the least.
+fn most_clustered(period: i64, coordinates: impl Fn(i64) -> Vec<i64>) -> i64 {
+    (0..period)
+        .min_by_key(|&second| spread(&coordinates(second)))
+        .unwrap()
+}
+
+/// The variance times the squared number of values, which keeps it an integer.
+fn spread(values: &[i64]) -> i64 {
+    let n = values.len() as i64;
+    let sum: i64 = values.iter().sum();
+    let sum_of_squares: i64 = values.iter().map(|v| v * v).sum();
+    n * sum_of_squares - sum * sum
+}
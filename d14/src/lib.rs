@@ -0,0 +1,397 @@
+use std::num::NonZeroU32;
+use utils::error::{input_lines, AocError, InputLine};
+use utils::parse::integers_exact;
+use utils::point::MapPoint;
+
+use utils::solution::{solve_timed, MaybeAnswer, Parameters, Solution, Variant};
+use utils::sparse_grid::SparseGrid;
+
+pub mod crt;
+
+/// Width and height of the space the robots move in on the real inputs. The example sets its
+/// smaller space with the `width` and `height` parameters.
+const WIDTH: u32 = 101;
+const HEIGHT: u32 = 103;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Robot {
+    pub position_x: i64,
+    pub position_y: i64,
+    pub velocity_x: i64,
+    pub velocity_y: i64,
+}
+
+impl Robot {
+    fn position(&self) -> MapPoint {
+        MapPoint::new(self.position_x, self.position_y)
+    }
+
+    fn is_inside(&self, width: i64, height: i64) -> bool {
+        (0..width).contains(&self.position_x) && (0..height).contains(&self.position_y)
+    }
+
+    fn tick(&mut self, width: i64, height: i64) {
+        self.position_x = (self.position_x + self.velocity_x).rem_euclid(width);
+        self.position_y = (self.position_y + self.velocity_y).rem_euclid(height);
+    }
+}
+
+impl TryFrom<InputLine<'_>> for Robot {
+    type Error = AocError;
+
+    fn try_from(line: InputLine<'_>) -> Result<Self, Self::Error> {
+        let [position_x, position_y, velocity_x, velocity_y] = integers_exact(&line)?;
+
+        Ok(Self {
+            position_x,
+            position_y,
+            velocity_y,
+            velocity_x,
+        })
+    }
+}
+
+/// The robots and the size of the space they move in.
+pub struct Lobby {
+    pub robots: Vec<Robot>,
+    pub width: i64,
+    pub height: i64,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Lobby;
+    type Answer1 = usize;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, AocError> {
+        let width = i64::from(
+            parameters
+                .get_or("width", NonZeroU32::new(WIDTH).unwrap())?
+                .get(),
+        );
+        let height = i64::from(
+            parameters
+                .get_or("height", NonZeroU32::new(HEIGHT).unwrap())?
+                .get(),
+        );
+
+        let mut robots = Vec::new();
+        for line in input_lines(input) {
+            let robot = Robot::try_from(line)?;
+            if !robot.is_inside(width, height) {
+                return Err(line.error(line.text, format!("outside of the {width}x{height} space")));
+            }
+            robots.push(robot);
+        }
+
+        // A robot moves the same with any velocity that differs by the size of the space, the
+        // reduced ones keep the positions from overflowing while they tick.
+        for robot in robots.iter_mut() {
+            robot.velocity_x = robot.velocity_x.rem_euclid(width);
+            robot.velocity_y = robot.velocity_y.rem_euclid(height);
+        }
+
+        Ok(Lobby {
+            robots,
+            width,
+            height,
+        })
+    }
+
+    fn part1(lobby: &Self::Input) -> Self::Answer1 {
+        part1(lobby)
+    }
+
+    fn part2(lobby: &Self::Input) -> Self::Answer2 {
+        MaybeAnswer(part2(lobby))
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day14>),
+        ("crt", solve_timed::<crt::Day14Crt>),
+    ]
+}
+
+pub fn part1(lobby: &Lobby) -> usize {
+    // Preamble
+    const SECONDS_TO_SIMULATE: usize = 100;
+
+    let mut robots = lobby.robots.clone();
+
+    for _i in 0..SECONDS_TO_SIMULATE {
+        for robot in robots.iter_mut() {
+            robot.tick(lobby.width, lobby.height);
+        }
+        // println!("Print Grid of Second: {_i}");
+    }
+    // print_grid(&robots);
+
+    // Solve
+    let (top_right, top_left, down_right, down_left) =
+        robots_in_quadrant(&robots, lobby.width / 2, lobby.height / 2);
+    // Result
+    // println!("TL: {top_left}, TR: {top_right}, DL: {down_left}, DR: {down_right}");
+    top_right * top_left * down_right * down_left
+}
+
+fn robots_in_quadrant(
+    robots: &[Robot],
+    middle_x: i64,
+    middle_y: i64,
+) -> (usize, usize, usize, usize) {
+    let mut top_left = 0;
+    let mut top_right = 0;
+    let mut down_left = 0;
+    let mut down_right = 0;
+
+    for robot in robots {
+        let x = robot.position_x;
+        let y = robot.position_y;
+        if y == middle_y || x == middle_x {
+            continue;
+        }
+
+        let top = y < middle_y;
+        let left = x < middle_x;
+
+        match (top, left) {
+            (true, true) => top_left += 1,
+            (true, false) => top_right += 1,
+            (false, true) => down_left += 1,
+            (false, false) => down_right += 1,
+        }
+    }
+    (top_left, top_right, down_left, down_right)
+}
+
+/// The first second the robots show the tree, `None` if they never do. Every robot is back at
+/// its start after `width * height` seconds, so nothing new can show up after that.
+pub fn part2(lobby: &Lobby) -> Option<usize> {
+    // Preamble
+    let (width, height) = (lobby.width, lobby.height);
+    let mut robots = lobby.robots.clone();
+
+    // let mut seen: HashMap<usize, Robot> = HashMap::new();
+    // let mut max = 0;
+
+    for i in 1..=(width * height) as usize {
+        for robot in robots.iter_mut() {
+            robot.tick(width, height);
+        }
+
+        if find_top_border(&robots_in_space(&robots), width / 2, height / 2) {
+            return Some(i);
+        }
+
+        // let mirror_count = count_mirrors_robots(&robots);
+
+        // if max < mirror_count{
+        // println!("Max mirror count: {mirror_count}");
+        // max = mirror_count;
+        // print_grid_to_file(&robots, i);
+        // }
+        // if mirror_count > 80 {
+        // print_grid_to_file(&robots, i + 1);
+        // break;
+        // }
+
+        // if robots_mirror_tree(&robots, &mut seen) {
+        //     let mirrored_ones: Vec<Robot> = seen.iter().map(|i| *i.1).collect();
+        //     print_grid_to_file(&mirrored_ones, i);
+        //     break;
+        // }
+    }
+
+    None
+}
+
+// fn print_grid(robots: &[Robot]) {
+//     for y in 0..SPACE_Y {
+//         for x in 0..SPACE_X {
+//             let count = robots.iter().filter(|r| r.is_on(x, y)).count();
+//             if count == 0 {
+//                 print!(".");
+//             } else {
+//                 print!("{count}");
+//             }
+//         }
+//         println!()
+//     }
+// }
+
+// fn print_grid_to_file(robots: &[Robot], round: usize) {
+//     let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+//         .join("..")
+//         .join("target")
+//         .join("d14")
+//         .join(format!("{round:<03}.txt"));
+//
+//     std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+//
+//     let mut writer = File::create_buffered(path).unwrap();
+//
+//     for y in 0..SPACE_Y {
+//         for x in 0..SPACE_X {
+//             if x == MIDDLE_X {
+//                 writer.write("|".as_bytes()).unwrap();
+//                 continue;
+//             }
+//             let count = robots.iter().filter(|r| r.is_on(x, y)).count();
+//             if count == 0 {
+//                 writer.write(".".as_bytes()).unwrap();
+//             } else {
+//                 writer.write(count.to_string().as_bytes()).unwrap();
+//             }
+//         }
+//         writer.write("\n".as_bytes()).unwrap();
+//     }
+// }
+
+/// Number of robots on every occupied tile.
+fn robots_in_space(robots: &[Robot]) -> SparseGrid<usize> {
+    let mut rtn = SparseGrid::new();
+    for robot in robots {
+        *rtn.get_or_insert_with(robot.position(), || 0) += 1;
+    }
+    rtn
+}
+
+fn find_top_border(space: &SparseGrid<usize>, middle_x: i64, middle_y: i64) -> bool {
+    space.points().any(|position| {
+        position.x <= middle_x
+            && position.y <= middle_y
+            && (1..10).all(|dx| space.contains(&MapPoint::new(position.x + dx, position.y)))
+    })
+}
+
+// fn count_mirrors_robots(robots: &Vec<Robot>) -> usize {
+//     let mut rtn = 0;
+//     for robot in robots {
+//         if MIDDLE_X == robot.position_x {
+//             rtn += 1;
+//         }
+//         if MIDDLE_X < robot.position_x {
+//             continue;
+//         }
+//
+//         let distance = MIDDLE_X - robot.position_x;
+//         let new_x = MIDDLE_X + distance;
+//
+//         if robots
+//             .iter()
+//             .enumerate()
+//             .find(|r| r.1.is_on(new_x, robot.position_y))
+//             .is_some()
+//         {
+//             rtn += 1;
+//         }
+//     }
+//     rtn
+// }
+
+// fn robots_mirror_tree(robots: &Vec<Robot>, seen: &mut HashMap<usize, Robot>) -> bool {
+//     let mut rtn = true;
+//     for (i, robot) in robots.iter().enumerate() {
+//         if MIDDLE_X > robot.position_x{
+//             continue
+//         }
+//
+//         // if MIDDLE_X.abs_diff(robot.position_x) as i64 >= robot.position_y {
+//         //     continue
+//         // }
+//         if seen.contains_key(&i) {
+//             continue;
+//         }
+//
+//         // if MIDDLE_X == robot.position_x || MIDDLE_Y == robot.position_y {
+//         //     continue;
+//         // }
+//
+//         let is_left = robot.position_x < MIDDLE_X;
+//
+//         let new_x = if is_left {
+//             let distance = MIDDLE_X - robot.position_x;
+//             MIDDLE_X + distance
+//         } else {
+//             let distance = robot.position_x - MIDDLE_X;
+//             MIDDLE_X - distance
+//         };
+//         // for x in 0..SPACE_X {
+//         //     if new_x == x {
+//         //         print!("n");
+//         //         continue
+//         //     }
+//         //     if MIDDLE_X == x {
+//         //         print!("|");
+//         //         continue
+//         //     }
+//         //     if robot.position_x == x {
+//         //         print!("r");
+//         //         continue
+//         //     }
+//         //     print!(".");
+//         // }
+//         // println!();
+//
+//         if let Some((idx, inner_robot)) = robots
+//             .iter()
+//             .enumerate()
+//             .find(|r| i != r.0 && r.1.is_on(new_x, robot.position_y))
+//         {
+//             if i == idx{
+//                 println!("Fishy");
+//             }
+//             seen.insert(i, *robot);
+//             seen.insert(idx, *inner_robot);
+//         } else {
+//             rtn = false;
+//         }
+//     }
+//     rtn
+// }
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{
+        assert_part1, assert_part2, assert_variants_agree, solve_part2, MaybeAnswer,
+    };
+
+    use crate::{variants, Day14};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day14>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        // The robots of the example never form a tree, the search has to give up.
+        assert_eq!(
+            solve_part2::<Day14>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap())
+                .unwrap(),
+            MaybeAnswer(None)
+        );
+    }
+
+    #[test]
+    fn test_input_2_part_2() {
+        assert_part2::<Day14>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap(),
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::{HashSet, VecDeque};
+
+use utils::error::AocError;
+use utils::grid::Grid;
+use utils::grid_direction::GridDirection;
+use utils::grid_point::GridPoint;
+use utils::parse::{grid_and_trailer, require_markers};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Warehouse {
+    pub map: Grid<char>,
+    pub moves: Vec<GridDirection>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (map, trailer) = grid_and_trailer(input)?;
+        require_markers(input, &map, &['@'])?;
+
+        let mut moves: Vec<GridDirection> = Vec::new();
+        for line in trailer {
+            let text = line.text.trim();
+            let start = line.position_of(text).column;
+            for (i, c) in text.chars().enumerate() {
+                let direction = GridDirection::from_arrow(c).ok_or(AocError::UnexpectedChar {
+                    found: c,
+                    position: Some(line.position(start + i)),
+                })?;
+                moves.push(direction);
+            }
+        }
+
+        Ok(Warehouse { map, moves })
+    }
+
+    fn part1(warehouse: &Self::Input) -> Self::Answer1 {
+        part1(warehouse)
+    }
+
+    fn part2(warehouse: &Self::Input) -> Self::Answer2 {
+        part2(warehouse)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day15>)]
+}
+
+pub fn part1(warehouse: &Warehouse) -> usize {
+    // Prepare
+    let mut grid = warehouse.map.clone();
+    let mut robot = grid.find_first(&'@').expect("parse checks for the robot");
+
+    // Solve
+    // println!("Initial state:");
+    // grid.print_data();
+    // println!();
+
+    for &m in &warehouse.moves {
+        robot = do_move(robot, &mut grid, m);
+
+        // println!("Move {}:", m);
+        // grid.print_data();
+        // println!();
+    }
+
+    // Result
+    // println!("Final state:");
+    // grid.print_data();
+    let mut result = 0;
+
+    for y in 0..grid.get_max_y() {
+        for x in 0..grid.get_max_x() {
+            if grid[GridPoint::new(x, y)] == 'O' {
+                result += 100 * y + x;
+            }
+        }
+    }
+
+    result
+}
+
+/// Every tile of the map is twice as wide in the second warehouse.
+fn widen(c: char) -> [char; 2] {
+    match c {
+        '#' => ['#', '#'],
+        '.' => ['.', '.'],
+        'O' => ['[', ']'],
+        '@' => ['@', '.'],
+        _ => panic!("Should not be here!"),
+    }
+}
+
+pub fn part2(warehouse: &Warehouse) -> usize {
+    // Prepare
+    let mut grid = warehouse.map.expand_with(widen);
+    let mut robot = grid.find_first(&'@').expect("parse checks for the robot");
+
+    // Solve
+    // println!("Initial state:");
+    // grid.print_data();
+    // println!();
+
+    for &m in &warehouse.moves {
+        robot = do_move_2(robot, &mut grid, m);
+
+        // if idx == 21 {
+        // println!("Move {} after {} rounds:", m, idx + 1);
+        // grid.print_data();
+        // println!();
+        // break;
+        // }
+    }
+
+    // Result
+    // println!("Final state:");
+    // grid.print_data();
+    let mut result = 0;
+
+    for y in 0..grid.get_max_y() {
+        for x in 0..grid.get_max_x() {
+            if grid[GridPoint::new(x, y)] == '[' {
+                result += 100 * y + x;
+            }
+        }
+    }
+
+    result
+}
+
+#[derive(Debug)]
+struct Doings {
+    from: GridPoint,
+    to: GridPoint,
+}
+
+impl Doings {
+    fn new(from: GridPoint, to: GridPoint) -> Self {
+        Self { from, to }
+    }
+}
+
+fn do_move_2(robot: GridPoint, grid: &mut Grid<char>, direction: GridDirection) -> GridPoint {
+    let mut to_dos = Vec::new();
+    let mut seen = HashSet::new();
+
+    let mut queue: VecDeque<(GridPoint, GridPoint)> = VecDeque::new();
+    queue.push_back((robot, robot.next_by_direction(&direction)));
+
+    while let Some((prev, next)) = queue.pop_front() {
+        if !seen.insert(prev) {
+            continue;
+        }
+        match direction {
+            GridDirection::Up | GridDirection::Down => match grid[next] {
+                '#' => {
+                    return robot;
+                }
+                '[' => {
+                    to_dos.push(Doings::new(prev, next));
+                    queue.push_back((next, next.next_by_direction(&direction)));
+                    let next_other = next.next_right();
+                    queue.push_back((next_other, next_other.next_by_direction(&direction)));
+                }
+                ']' => {
+                    to_dos.push(Doings::new(prev, next));
+                    queue.push_back((next, next.next_by_direction(&direction)));
+                    let next_other = next.next_left();
+                    queue.push_back((next_other, next_other.next_by_direction(&direction)));
+                }
+                '.' => {
+                    to_dos.push(Doings::new(prev, next));
+                }
+                _ => {
+                    panic!("Should not be here. The current Char is {}", grid[next]);
+                }
+            },
+            GridDirection::Right | GridDirection::Left => match grid[next] {
+                '#' => {
+                    return robot;
+                }
+                '[' | ']' => {
+                    to_dos.push(Doings::new(prev, next));
+                    queue.push_back((next, next.next_by_direction(&direction)));
+                }
+                '.' => {
+                    to_dos.push(Doings::new(prev, next));
+                }
+                _ => {
+                    panic!("Should not be here. The current Char is {}", grid[next]);
+                }
+            },
+        }
+    }
+
+    while let Some(to_do) = to_dos.pop() {
+        let to_char = grid[to_do.to];
+        let from_char = grid[to_do.from];
+        grid.set_from_point(&to_do.to, from_char);
+        grid.set_from_point(&to_do.from, to_char);
+        // grid.print_data();
+        // println!()
+    }
+
+    robot.next_by_direction(&direction)
+}
+
+fn do_move(mut robot: GridPoint, grid: &mut Grid<char>, direction: GridDirection) -> GridPoint {
+    let mut positions = vec![];
+    let mut current = robot.next_by_direction(&direction);
+    loop {
+        match grid[current] {
+            '#' => return robot,
+            'O' => {
+                positions.push(current);
+                current = current.next_by_direction(&direction);
+            }
+            '.' => {
+                grid.set_from_point(&robot, '.');
+                let mut is_first = true;
+                positions.push(current);
+                for current_box_position in positions {
+                    if is_first {
+                        grid.set_from_point(&current_box_position, '@');
+                        robot = current_box_position;
+                        is_first = false;
+                    } else {
+                        grid.set_from_point(&current_box_position, 'O');
+                    }
+                }
+                return robot;
+            }
+            _ => {
+                panic!("Should not be here! The current input is {}", grid[current]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day15;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day15>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day15>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
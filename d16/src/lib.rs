@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use utils::error::AocError;
+use utils::grid::Grid;
+use utils::grid_direction::GridDirection;
+use utils::grid_point::GridPoint;
+use utils::parse::require_markers;
+use utils::search::SearchResult;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod parallel;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = input.parse()?;
+        require_markers(input, &grid, &['S', 'E'])?;
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day16>),
+        ("rayon", solve_timed::<parallel::Day16Rayon>),
+    ]
+}
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    // Solve
+    let result = search_maze(grid);
+
+    // Result
+    result.cost().unwrap()
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    // Solve
+    let result = search_maze(grid);
+
+    // Result
+    let result_tiles: HashSet<GridPoint> = result
+        .nodes_on_optimal_paths()
+        .into_iter()
+        .map(|(position, _)| *position)
+        .collect();
+    result_tiles.len()
+}
+
+/// Searches the cheapest ways from the start facing east to the end facing any direction.
+fn search_maze(grid: &Grid<char>) -> SearchResult<(GridPoint, GridDirection)> {
+    let start = grid.find_first(&'S').expect("parse checks for the start");
+    let end = grid.find_first(&'E').expect("parse checks for the end");
+
+    grid.dijkstra_with_state(
+        (start, GridDirection::Right),
+        |&(position, direction)| {
+            [
+                (position.next_by_direction(&direction), direction),
+                (position, direction.left()),
+                (position, direction.right()),
+            ]
+        },
+        |(_, from), (_, to), &cell| match cell {
+            '#' => None,
+            _ if from == to => Some(1),
+            _ => Some(1000),
+        },
+        |&(position, _)| position == end,
+    )
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day16};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day16>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+        assert_part1::<Day16>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day16>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+        assert_part2::<Day16>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap(),
+        );
+    }
+}
@@ -1,14 +1,17 @@
 use std::cmp::Ordering;
-use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::{Arc, RwLock};
+
 use rayon::Scope;
+use utils::error::AocError;
 use utils::grid::Grid;
 use utils::grid_direction::GridDirection;
 use utils::grid_point::GridPoint;
-use utils::{get_input_path, parse_into_char_vector_vector};
+use utils::solution::Solution;
+
+use crate::{part1, Day16};
 
 struct SearchContext {
     position: GridPoint,
@@ -24,7 +27,7 @@ impl PartialEq<Self> for SearchContext {
     fn eq(&self, other: &Self) -> bool {
         self.points == other.points
             && self.direction == other.direction
-            && self.position == self.position
+            && self.position == other.position
     }
 }
 
@@ -75,102 +78,39 @@ impl SearchContext {
     }
 }
 
-fn run(input_file: &Path) {
-    // Preamble
-    let mut results = Vec::new();
-
-    // Parse
-    let grid: Grid<char> = parse_into_char_vector_vector(input_file).into();
-
-    // Solve
-    let start = grid.find_first(&'S').unwrap();
-    let end = grid.find_first(&'E').unwrap();
-
-    let mut queue = BTreeSet::new();
-
-    queue.insert(SearchContext {
-        position: start,
-        direction: GridDirection::Right,
-        walked: vec![],
-        points: 0,
-        turns: 0,
-    });
-
-    let mut seen: HashMap<(GridPoint, GridDirection), usize> = HashMap::new();
-
-    while let Some(item) = queue.pop_first() {
-        if item.position == end {
-            results.push(item);
-            break;
-        }
-
-        if grid.data[item.position.y][item.position.x] == '#' {
-            continue;
-        }
-
-        let key = (item.position, item.direction);
-
-        // if seen.get(&key).is_some()
-        if seen.get(&key).is_some_and(|o| o < &item.points) {
-            continue;
-        } else {
-            seen.insert(key, item.points);
-        }
+/// Spawns every branch of the part 2 search as its own rayon task.
+pub struct Day16Rayon;
 
-        let next_forward = item.next_forward();
-        let next_turn_left = item.next_turn_left();
-        let next_turn_right = item.next_turn_right();
+impl Solution for Day16Rayon {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-        queue.insert(next_forward);
-        queue.insert(next_turn_left);
-        queue.insert(next_turn_right);
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day16::parse(input)
+    }
 
-        // println!("Order");
-        // for item in queue.iter() {
-        //     println!(
-        //         "Direction: {}, Position: {:?}, Points: {}",
-        //         item.direction, item.position, item.points
-        //     );
-        // }
-        // println!();
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        part1(grid)
     }
 
-    // Result
-    let result = results.iter().min_by_key(|s| s.points).unwrap();
-    println!(
-        "Resulting Path has {} turns and took {} steps.",
-        result.turns,
-        result.walked.len()
-    );
-    println!("Result of part 1 is {}", result.points);
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        part2(grid)
+    }
 }
 
-fn run2(input_file: &Path) {
-    // Preamble
-    // Parse
-    let grid: Grid<char> = parse_into_char_vector_vector(input_file).into();
-
+pub fn part2(grid: &Grid<char>) -> usize {
     // Solve
 
-    let start = grid.find_first(&'S').unwrap();
-    let end = grid.find_first(&'E').unwrap();
-
-    let mut queue = VecDeque::new();
-
-    queue.push_back(SearchContext {
-        position: start,
-        direction: GridDirection::Right,
-        walked: vec![],
-        points: 0,
-        turns: 0,
-    });
+    let start = grid.find_first(&'S').expect("parse checks for the start");
+    let end = grid.find_first(&'E').expect("parse checks for the end");
 
     let seen: Arc<RwLock<HashMap<(GridPoint, GridDirection), usize>>> =
         Arc::new(RwLock::new(HashMap::new()));
     // let min = AtomicUsize::new(102488);
     let min = AtomicUsize::new(usize::MAX);
 
-    let grid_arc = Arc::new(grid);
+    let grid_arc = Arc::new(grid.clone());
     let results = Arc::new(RwLock::new(Vec::new()));
     let pool = rayon::ThreadPoolBuilder::new().build().unwrap();
     pool.scope(|scope| {
@@ -187,7 +127,7 @@ fn run2(input_file: &Path) {
             &min,
             end,
             results.clone(),
-            scope
+            scope,
         )
     });
 
@@ -204,7 +144,7 @@ fn run2(input_file: &Path) {
         })
     }
 
-    println!("Result of part 2 is {}", result_tiles.len());
+    result_tiles.len()
 }
 
 fn search_context<'a>(
@@ -214,20 +154,20 @@ fn search_context<'a>(
     min: &'a AtomicUsize,
     end: GridPoint,
     results: Arc<RwLock<Vec<SearchContext>>>,
-    scope: &Scope<'a>
+    scope: &Scope<'a>,
 ) {
     if item.points > min.load(Relaxed) {
         return;
     }
 
     if item.position == end {
-        min.store(item.points.min(min.load(Relaxed)), Relaxed);
+        min.fetch_min(item.points, Relaxed);
         results.write().unwrap().push(item);
-        println!("Found exit after {}", min.load(Relaxed));
+        // println!("Found exit after {}", min.load(Relaxed));
         return;
     }
 
-    if grid.data[item.position.y][item.position.x] == '#' {
+    if grid[item.position] == '#' {
         return;
     }
 
@@ -260,37 +200,37 @@ fn search_context<'a>(
     let results_left = results.clone();
     let results_right = results.clone();
 
-    scope.spawn(move |inner_scope| search_context(next_turn_left, grid_left, seen_left, min, end, results_left, inner_scope) );
-    scope.spawn(move |inner_scope| search_context(next_turn_right, grid_right, seen_right, min, end, results_right, inner_scope) );
-    scope.spawn(move |inner_scope| search_context(next_forward, grid_forward, seen_forward, min, end, results_forward, inner_scope) );
-}
-
-fn main() {
-    let input_file = get_input_path(env!("CARGO_MANIFEST_DIR"));
-
-    println!("Running {}", env!("CARGO_PKG_NAME"));
-    println!("InputFile: {}", input_file.display());
-
-    run(input_file.as_path());
-    run2(input_file.as_path());
-}
-
-#[cfg(test)]
-mod main_test {
-    use utils::{get_test_input_2_path, get_test_input_path};
-
-    use crate::run;
-    use crate::run2;
-
-    #[test]
-    fn test_input_part_1() {
-        run(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-        run(&get_test_input_2_path(env!("CARGO_MANIFEST_DIR")));
-    }
-
-    #[test]
-    fn test_input_part_2() {
-        run2(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-        run2(&get_test_input_2_path(env!("CARGO_MANIFEST_DIR")));
-    }
+    scope.spawn(move |inner_scope| {
+        search_context(
+            next_turn_left,
+            grid_left,
+            seen_left,
+            min,
+            end,
+            results_left,
+            inner_scope,
+        )
+    });
+    scope.spawn(move |inner_scope| {
+        search_context(
+            next_turn_right,
+            grid_right,
+            seen_right,
+            min,
+            end,
+            results_right,
+            inner_scope,
+        )
+    });
+    scope.spawn(move |inner_scope| {
+        search_context(
+            next_forward,
+            grid_forward,
+            seen_forward,
+            min,
+            end,
+            results_forward,
+            inner_scope,
+        )
+    });
 }
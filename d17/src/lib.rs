@@ -0,0 +1,258 @@
+use std::ops::BitXor;
+
+use utils::error::{unexpected_end, AocError, InputLine};
+use utils::parse::{integers_exact, key_value, list, split_sections};
+use utils::solution::{solve_timed, Solution, Variant};
+
+#[derive(Debug)]
+enum OpCodes {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VM {
+    pub pos: u64,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub program: Vec<u64>,
+    pub output: Vec<u64>,
+}
+
+impl VM {
+    pub fn run(&mut self) {
+        while self.can_continue() {
+            self.operate();
+        }
+    }
+
+    pub fn operate(&mut self) {
+        let opcode: OpCodes = self.program[self.pos as usize].into();
+        match opcode {
+            OpCodes::Adv => self.adv(),
+            OpCodes::Bxl => self.bxl(),
+            OpCodes::Bst => self.bst(),
+            OpCodes::Jnz => self.jnz(),
+            OpCodes::Bxc => self.bxc(),
+            OpCodes::Out => self.out(),
+            OpCodes::Bdv => self.bdv(),
+            OpCodes::Cdv => self.cdv(),
+        }
+    }
+    // pub(crate) fn is_halt(&self) -> bool {
+    //     self.pos >= self.program.len() as u64
+    // }
+    pub fn can_continue(&self) -> bool {
+        self.pos < self.program.len() as u64
+    }
+    pub fn bdv(&mut self) {
+        let operand = self.get_combo();
+        let result = self.a / 2_u64.pow(operand as u32);
+        self.b = result;
+        self.pos += 2;
+    }
+    pub fn cdv(&mut self) {
+        let operand = self.get_combo();
+        let result = self.a / 2_u64.pow(operand as u32);
+        self.c = result;
+        self.pos += 2;
+    }
+    pub fn out(&mut self) {
+        let combo = self.get_combo();
+        let result = combo % 8;
+        self.output.push(result);
+
+        self.pos += 2;
+    }
+    pub fn bxc(&mut self) {
+        self.b = self.b.bitxor(self.c);
+
+        self.pos += 2;
+    }
+    pub fn jnz(&mut self) {
+        if self.a == 0 {
+            self.pos += 2;
+            return;
+        }
+
+        self.pos = self.get_literal();
+    }
+    pub fn bst(&mut self) {
+        let combo = self.get_combo();
+        self.b = combo % 8;
+
+        self.pos += 2;
+    }
+    pub fn bxl(&mut self) {
+        let result = self.b.bitxor(self.get_literal());
+        self.b = result;
+
+        self.pos += 2;
+    }
+    pub fn adv(&mut self) {
+        let operand = self.get_combo();
+        let result = self.a / 2_u64.pow(operand as u32);
+        self.a = result;
+
+        self.pos += 2;
+    }
+    pub fn get_literal(&self) -> u64 {
+        self.program[self.pos as usize + 1]
+    }
+    pub fn get_combo(&self) -> u64 {
+        match self.program[self.pos as usize + 1] {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            7 => panic!("This is not a valid combo value. The value is 7"),
+            _ => panic!(
+                "This is not a valid combo value. The value is {}",
+                &self.program[self.pos as usize]
+            ),
+        }
+    }
+    pub fn create_result(&self) -> String {
+        let mut rtn = String::with_capacity(self.output.len() * 2);
+
+        let mut is_not_first = false;
+
+        for i in &self.output {
+            if is_not_first {
+                rtn.push(',');
+            }
+            is_not_first = true;
+
+            let s = i % 8;
+            rtn.push_str(s.to_string().as_str());
+        }
+
+        rtn
+    }
+}
+
+impl From<u64> for OpCodes {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => OpCodes::Adv,
+            1 => OpCodes::Bxl,
+            2 => OpCodes::Bst,
+            3 => OpCodes::Jnz,
+            4 => OpCodes::Bxc,
+            5 => OpCodes::Out,
+            6 => OpCodes::Bdv,
+            7 => OpCodes::Cdv,
+            _ => panic!("Should not be here!"),
+        }
+    }
+}
+
+fn parse_register<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = &'a InputLine<'a>>,
+    what: &str,
+) -> Result<u64, AocError> {
+    let line = lines.next().ok_or_else(|| unexpected_end(input, what))?;
+    let [value] = integers_exact(line)?;
+    Ok(value)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = VM;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let [registers, program] = split_sections(input, ["registers", "program"])?;
+        let mut lines = registers.iter();
+
+        let mut vm = VM {
+            pos: 0,
+            a: parse_register(input, &mut lines, "register A")?,
+            b: parse_register(input, &mut lines, "register B")?,
+            c: parse_register(input, &mut lines, "register C")?,
+            program: vec![],
+            output: vec![],
+        };
+
+        let line = program[0];
+        let (_, program) = key_value(&line, ":")?;
+        vm.program = list(&line, program, ",")?;
+        if let Some(value) = vm.program.iter().find(|&&value| value > 7) {
+            return Err(line.error(program, format!("{value} is not a 3-bit number")));
+        }
+
+        Ok(vm)
+    }
+
+    fn part1(vm: &Self::Input) -> Self::Answer1 {
+        part1(vm)
+    }
+
+    fn part2(vm: &Self::Input) -> Self::Answer2 {
+        part2(vm)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day17>)]
+}
+
+pub fn part1(vm: &VM) -> String {
+    // Solve
+    let mut vm = vm.clone();
+    vm.run();
+
+    // Result
+    vm.create_result()
+}
+
+pub fn part2(vm: &VM) -> u64 {
+    // Solve
+    let mut a = 0;
+    for i in (0..vm.program.len()).rev() {
+        a <<= 3;
+        loop {
+            let mut current_vm = vm.clone();
+            current_vm.a = a;
+            current_vm.run();
+            if current_vm.output == vm.program[i..] {
+                break;
+            }
+            a += 1;
+        }
+    }
+
+    // Result
+    a
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day17;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day17>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day17>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+}
@@ -0,0 +1,154 @@
+use std::num::NonZeroUsize;
+use utils::error::{input_lines, unexpected_end, AocError};
+use utils::grid::{Adjacency, Grid};
+use utils::grid_point::GridPoint;
+
+use utils::solution::{solve_timed, MaybeAnswer, Parameters, Solution, Variant};
+
+/// Size of the memory space and how many bytes have fallen for part 1 on the real inputs. The
+/// example sets its smaller ones with the `size` and `bytes` parameters.
+const SIZE: usize = 71;
+const USED_BYTES: usize = 1024;
+
+/// The falling bytes and the memory space they fall into.
+pub struct Memory {
+    pub bytes: Vec<GridPoint>,
+    pub size: usize,
+    pub used_bytes: usize,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+    type Answer1 = MaybeAnswer<usize>;
+    type Answer2 = MaybeAnswer<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, AocError> {
+        let size = parameters
+            .get_or("size", NonZeroUsize::new(SIZE).unwrap())?
+            .get();
+        let used_bytes = parameters.get_or("bytes", USED_BYTES)?;
+        let mut bytes: Vec<GridPoint> = Vec::new();
+
+        for line in input_lines(input) {
+            let text = line.text.trim();
+            let mut split = text.split(",");
+            let x = line.parse_next(&mut split, "x")?;
+            let y = line.parse_next(&mut split, "y")?;
+            if x >= size || y >= size {
+                return Err(line.error(text, format!("outside of the {size}x{size} memory space")));
+            }
+            bytes.push(GridPoint { x, y })
+        }
+
+        if bytes.len() < used_bytes {
+            return Err(unexpected_end(input, &format!("{used_bytes} bytes")));
+        }
+
+        Ok(Memory {
+            bytes,
+            size,
+            used_bytes,
+        })
+    }
+
+    fn part1(memory: &Self::Input) -> Self::Answer1 {
+        MaybeAnswer(part1(memory))
+    }
+
+    fn part2(memory: &Self::Input) -> Self::Answer2 {
+        MaybeAnswer(part2(memory))
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day18>)]
+}
+
+/// Steps to the exit after the first bytes fell, `None` if they already cut it off.
+pub fn part1(memory: &Memory) -> Option<usize> {
+    let (bytes, size) = (&memory.bytes, memory.size);
+
+    // let start = GridPoint::new(0, 0);
+    let end = GridPoint::new(size - 1, size - 1);
+
+    // Preamble
+    let mut grid: Grid<char> = Grid::with_width_height(size, size, '.');
+
+    for byte_coords in &bytes[0..memory.used_bytes] {
+        grid.set_from_point(byte_coords, '#');
+    }
+
+    let result = find_exit(&grid, end);
+
+    // grid.print_data();
+
+    // Solve
+    let result = result.map(|path| path.len() - 1);
+    // Result
+    result
+}
+
+/// The first byte that cuts off the exit, `None` if the exit can still be reached after all of
+/// them fell.
+pub fn part2(memory: &Memory) -> Option<String> {
+    let (bytes, size) = (&memory.bytes, memory.size);
+
+    // let start = GridPoint::new(0, 0);
+    let end = GridPoint::new(size - 1, size - 1);
+
+    // Preamble
+    let mut grid: Grid<char> = Grid::with_width_height(size, size, '.');
+
+    let mut last_winning_path = Vec::new();
+    let mut auto_ok = false;
+    for byte_coords in bytes {
+        // println!("Add # at {},{}", byte_coords.x, byte_coords.y);
+        grid.set_from_point(byte_coords, '#');
+        auto_ok = auto_ok && !last_winning_path.contains(byte_coords);
+
+        if auto_ok {
+            continue;
+        }
+
+        // Solve
+        let Some(winning_path) = find_exit(&grid, end) else {
+            // Result
+            return Some(format!("{},{}", byte_coords.x, byte_coords.y));
+        };
+        last_winning_path = winning_path;
+        auto_ok = true;
+    }
+
+    None
+}
+
+/// One of the shortest ways from the top left corner to `end` around the fallen bytes.
+fn find_exit(grid: &Grid<char>, end: GridPoint) -> Option<Vec<GridPoint>> {
+    grid.shortest_path(GridPoint::new(0, 0), end, Adjacency::FourWay, |_, &c| {
+        c != '#'
+    })
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day18;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day18>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day18>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
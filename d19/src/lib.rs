@@ -0,0 +1,170 @@
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::AddAssign;
+
+use utils::error::AocError;
+use utils::parse::{list, split_sections};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Towels {
+    pub patterns: Vec<String>,
+    pub designs: Vec<String>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Towels;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let [pattern_lines, design_lines] = split_sections(input, ["towel patterns", "designs"])?;
+
+        let line = pattern_lines[0];
+        let patterns = list(&line, line.text.trim(), ",")?;
+        let designs = design_lines
+            .iter()
+            .map(|line| line.text.trim().to_string())
+            .collect();
+
+        Ok(Towels { patterns, designs })
+    }
+
+    fn part1(towels: &Self::Input) -> Self::Answer1 {
+        part1(towels)
+    }
+
+    fn part2(towels: &Self::Input) -> Self::Answer2 {
+        part2(towels)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day19>)]
+}
+
+pub fn part1(towels: &Towels) -> usize {
+    // Preamble
+    let Towels { patterns, designs } = towels;
+
+    // Solve
+    let mut result = 0;
+    for design in designs {
+        if is_design_valid(design, patterns) {
+            result += 1;
+        }
+    }
+
+    // let result = patterns
+    //     .par_iter()
+    //     .filter(|design| is_design_valid(&design, &patterns))
+    //     .count();
+
+    // Result
+    result
+}
+
+pub fn part2(towels: &Towels) -> usize {
+    // Preamble
+    let Towels { patterns, designs } = towels;
+
+    // Solve
+    // let mut result = 0;
+    // for design in designs {
+    //     result += get_number_of_valid_designs(&design, &patterns);
+    // }
+
+    let result: usize = designs
+        .par_iter()
+        .map(|x| get_number_of_valid_designs(x, patterns))
+        .sum();
+
+    // let mut memo = HashMap::new();
+    // let mut result = 0;
+    // for design in &designs {
+    //     result += count_possible(design, &patterns, &mut memo);
+    // }
+
+    // Result
+    result
+}
+
+fn is_design_valid(design: &str, patterns: &[String]) -> bool {
+    let mut queue: BTreeSet<usize> = BTreeSet::new();
+
+    queue.insert(0);
+
+    while let Some(position) = queue.pop_last() {
+        if design.len() == position {
+            return true;
+        }
+        let string_start = &design[position..];
+
+        for pattern in patterns {
+            if string_start.starts_with(pattern) {
+                queue.insert(position + pattern.len());
+            }
+        }
+    }
+    false
+}
+
+fn get_number_of_valid_designs(design: &str, patterns: &[String]) -> usize {
+    let mut queue: BTreeMap<usize, usize> = BTreeMap::new();
+
+    queue.insert(0, 1);
+
+    let mut rtn = 0;
+
+    while let Some((position, count)) = queue.pop_first() {
+        if design.len() == position {
+            rtn += count;
+        }
+        let string_start = &design[position..];
+
+        for pattern in patterns {
+            if string_start.starts_with(pattern) {
+                queue
+                    .entry(position + pattern.len())
+                    .or_default()
+                    .add_assign(count);
+            }
+        }
+    }
+    rtn
+}
+
+// fn count_possible<'a>(pattern: &'a str, towels: &Vec<String>, memo: &mut HashMap<&'a str, u64>) -> u64 {
+//     if let Some(&c) = memo.get(pattern) { return c; }
+//
+//     if pattern.trim().is_empty() { return 1; }
+//
+//     let mut count = 0;
+//     for towel in towels {
+//         if pattern.starts_with(towel) {
+//             count += count_possible(&pattern[towel.len()..], &towels, memo);
+//         }
+//     }
+//
+//     *memo.entry(pattern).or_insert(0) += count;
+//     count
+// }
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day19;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day19>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day19>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
+use utils::error::AocError;
+use utils::grid::{Adjacency, Grid};
+use utils::grid_point::GridPoint;
+use utils::parse::require_markers;
+use utils::solution::{solve_timed, Parameters, Solution, Variant};
+
+pub mod parallel;
+pub mod stack;
+
+/// The picoseconds a cheat has to save to be counted on the real inputs. The example sets a lower
+/// threshold with the `min_saved` parameter, as no cheat on its small track saves that much.
+const MIN_SAVED: usize = 100;
+
+/// The race track and how many picoseconds a cheat has to save to be counted.
+pub struct RaceTrack {
+    pub grid: Grid<char>,
+    pub min_saved: usize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = RaceTrack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, AocError> {
+        let grid = input.parse()?;
+        require_markers(input, &grid, &['S', 'E'])?;
+        Ok(RaceTrack {
+            grid,
+            min_saved: parameters.get_or("min_saved", MIN_SAVED)?,
+        })
+    }
+
+    fn part1(track: &Self::Input) -> Self::Answer1 {
+        part1(track)
+    }
+
+    fn part2(track: &Self::Input) -> Self::Answer2 {
+        part2(track)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day20>),
+        ("stack", solve_timed::<stack::Day20Stack>),
+        ("rayon", solve_timed::<parallel::Day20Rayon>),
+    ]
+}
+
+pub fn part1(track: &RaceTrack) -> usize {
+    // Prepare
+    let (grid, min_saved) = (&track.grid, track.min_saved);
+    let start = grid.find_first(&'S').expect("parse checks for the start");
+    let end = grid.find_first(&'E').expect("parse checks for the end");
+
+    // Solve
+    let track = race_track(grid, start, end);
+
+    let mut results = Vec::new();
+
+    for i in 0..track.len() {
+        for oi in i + 4..track.len() {
+            let position = &track[i];
+            let other = &track[oi];
+            let distance = position.manhattan_distance(other);
+            if distance == 2 {
+                results.push(oi - i - 2);
+            }
+        }
+    }
+
+    let mut results_time: HashMap<usize, usize> = HashMap::new();
+    for result in results {
+        results_time.entry(result).or_default().add_assign(1);
+    }
+
+    // Result
+    results_time
+        .iter()
+        .filter_map(|x| if *x.0 >= min_saved { Some(x.1) } else { None })
+        .sum()
+}
+
+pub fn part2(track: &RaceTrack) -> usize {
+    // Prepare
+    let (grid, min_saved) = (&track.grid, track.min_saved);
+    let start = grid.find_first(&'S').expect("parse checks for the start");
+    let end = grid.find_first(&'E').expect("parse checks for the end");
+
+    // Solve
+    let track = race_track(grid, start, end);
+
+    let mut results = Vec::new();
+
+    for i in 0..track.len() {
+        for oi in i + min_saved..track.len() {
+            let position = &track[i];
+            let other = &track[oi];
+            let distance = position.manhattan_distance(other);
+            if distance <= 20 {
+                results.push(oi - i - distance);
+            }
+        }
+    }
+
+    let mut results_time: HashMap<usize, usize> = HashMap::new();
+    for result in results {
+        results_time.entry(result).or_default().add_assign(1);
+    }
+
+    let result: usize = results_time
+        .iter()
+        .filter_map(|x| if *x.0 >= min_saved { Some(x.1) } else { None })
+        .sum();
+
+    // Result
+    result
+}
+
+/// All positions of the track from `start` to `end`, both included.
+fn race_track(grid: &Grid<char>, start: GridPoint, end: GridPoint) -> Vec<GridPoint> {
+    grid.shortest_path(start, end, Adjacency::FourWay, |_, &c| c != '#')
+        .unwrap()
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day20};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day20>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day20>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+    }
+}
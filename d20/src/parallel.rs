@@ -1,11 +1,38 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::ops::AddAssign;
-use std::path::Path;
+
 use rayon::prelude::*;
-use utils::grid::Grid;
+use utils::error::AocError;
 use utils::grid_point::GridPoint;
-use utils::{get_input_path, parse_into_char_vector_vector};
+use utils::solution::{Parameters, Solution};
+
+use crate::{Day20, RaceTrack};
+
+/// Finds the race track with an ordered search and checks the cheats of every position in parallel.
+pub struct Day20Rayon;
+
+impl Solution for Day20Rayon {
+    type Input = RaceTrack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day20::parse(input)
+    }
+
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, AocError> {
+        Day20::parse_with(input, parameters)
+    }
+
+    fn part1(track: &Self::Input) -> Self::Answer1 {
+        part1(track)
+    }
+
+    fn part2(track: &Self::Input) -> Self::Answer2 {
+        part2(track)
+    }
+}
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 struct SearchContext {
@@ -22,7 +49,8 @@ impl PartialOrd for SearchContext {
 
 impl Ord for SearchContext {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.steps.cmp(&other.steps)
+        self.steps
+            .cmp(&other.steps)
             .then_with(|| self.position.cmp(&other.position))
             .then_with(|| self.previous_position.cmp(&other.previous_position))
     }
@@ -44,11 +72,11 @@ impl Ord for SearchContext {
 //     }
 // }
 
-fn run(input_file: &Path) {
-    // Parse
-    let grid: Grid<char> = parse_into_char_vector_vector(input_file).into();
-    let start = grid.find_first(&'S').unwrap();
-    let end = grid.find_first(&'E').unwrap();
+pub fn part1(track: &RaceTrack) -> usize {
+    // Prepare
+    let (grid, min_saved) = (&track.grid, track.min_saved);
+    let start = grid.find_first(&'S').expect("parse checks for the start");
+    let end = grid.find_first(&'E').expect("parse checks for the end");
 
     // Solve
     let mut queue = BTreeSet::new();
@@ -67,7 +95,7 @@ fn run(input_file: &Path) {
             break;
         }
 
-        if grid.data[context.position.y][context.position.x] == '#' {
+        if grid[context.position] == '#' {
             continue;
         }
 
@@ -97,7 +125,7 @@ fn run(input_file: &Path) {
     //     for oi in i + 4..context.previous_position.len() {
     //         let position = &context.previous_position[i];
     //         let other = &context.previous_position[oi];
-    //         let distance = position.manhatten_distance(other);
+    //         let distance = position.manhattan_distance(other);
     //         if distance == 2 {
     //             results.push((oi - i - 2, *position, *other));
     //         }
@@ -112,7 +140,7 @@ fn run(input_file: &Path) {
                 for oi in i + 3..context.previous_position.len() {
                     let position = &context.previous_position[i];
                     let other = &context.previous_position[oi];
-                    let distance = position.manhatten_distance(other);
+                    let distance = position.manhattan_distance(other);
                     if distance <= 2 {
                         results.push((oi - i - 2, *position, *other));
                     }
@@ -133,27 +161,24 @@ fn run(input_file: &Path) {
     //     } else {
     //         println!("There are {} cheats that save {} picoseconds.", i.1, i.0);
     //     }
-    //     if i.0 >= 100 {
+    //     if i.0 >= min_saved {
     //         result += i.1;
     //     }
     // }
 
     // Result
-    let result:usize = results_time.iter().filter_map(|x| {
-        if *x.0 >= 100 {
-            Some(x.1)
-        } else {
-            None
-        }
-    }).sum();
-    println!("Result of part 1 is {}", result);
+    let result: usize = results_time
+        .iter()
+        .filter_map(|x| if *x.0 >= min_saved { Some(x.1) } else { None })
+        .sum();
+    result
 }
 
-fn run2(input_file: &Path) {
-    // Parse
-    let grid: Grid<char> = parse_into_char_vector_vector(input_file).into();
-    let start = grid.find_first(&'S').unwrap();
-    let end = grid.find_first(&'E').unwrap();
+pub fn part2(track: &RaceTrack) -> usize {
+    // Prepare
+    let (grid, min_saved) = (&track.grid, track.min_saved);
+    let start = grid.find_first(&'S').expect("parse checks for the start");
+    let end = grid.find_first(&'E').expect("parse checks for the end");
 
     // Solve
     let mut queue = BTreeSet::new();
@@ -172,7 +197,7 @@ fn run2(input_file: &Path) {
             break;
         }
 
-        if grid.data[context.position.y][context.position.x] == '#' {
+        if grid[context.position] == '#' {
             continue;
         }
 
@@ -201,10 +226,10 @@ fn run2(input_file: &Path) {
     (0..context.previous_position.len()).into_par_iter().map(
         |i|{
             let mut results = Vec::new();
-            for oi in i + 50..context.previous_position.len() {
+            for oi in i + min_saved..context.previous_position.len() {
                 let position = &context.previous_position[i];
                 let other = &context.previous_position[oi];
-                let distance = position.manhatten_distance(other);
+                let distance = position.manhattan_distance(other);
                 if distance <= 20 {
                     results.push((oi - i - distance, *position, *other));
                 }
@@ -228,47 +253,16 @@ fn run2(input_file: &Path) {
     //     } else {
     //         println!("There are {} cheats that save {} picoseconds.", i.1, i.0);
     //     }
-    //     if i.0 >= 100 {
+    //     if i.0 >= min_saved {
     //         result += i.1;
     //     }
     // }
 
-    let result:usize = results_time.iter().filter_map(|x| {
-        if *x.0 >= 100 {
-            Some(x.1)
-        } else {
-            None
-        }
-    }).sum();
+    let result: usize = results_time
+        .iter()
+        .filter_map(|x| if *x.0 >= min_saved { Some(x.1) } else { None })
+        .sum();
 
     // Result
-    println!("Result of part 2 is {}", result);
-}
-
-fn main() {
-    let input_file = get_input_path(env!("CARGO_MANIFEST_DIR"));
-
-    println!("Running {}", env!("CARGO_PKG_NAME"));
-    println!("InputFile: {}", input_file.display());
-
-    run(input_file.as_path());
-    run2(input_file.as_path());
-}
-
-#[cfg(test)]
-mod main_test {
-    use utils::get_test_input_path;
-
-    use crate::run;
-    use crate::run2;
-
-    #[test]
-    fn test_input_part_1() {
-        run(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-    }
-
-    #[test]
-    fn test_input_part_2() {
-        run2(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-    }
+    result
 }
@@ -1,12 +1,32 @@
-#![feature(array_windows)]
-
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
 
-use utils::get_input_path;
+use utils::error::AocError;
 use utils::grid_point::GridPoint;
+use utils::solution::{NoAnswer, Solution};
+
+use crate::{Code, Day21};
+
+/// Builds a single sequence per keypad by always moving vertically first when starting at `A`
+/// and horizontally first otherwise. Only solves part 1.
+pub struct Day21Greedy;
+
+impl Solution for Day21Greedy {
+    type Input = Vec<Code>;
+    type Answer1 = usize;
+    type Answer2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day21::parse(input)
+    }
+
+    fn part1(codes: &Self::Input) -> Self::Answer1 {
+        part1(codes)
+    }
+
+    fn part2(_codes: &Self::Input) -> Self::Answer2 {
+        NoAnswer
+    }
+}
 
 fn get_num_pad() -> HashMap<u8, GridPoint> {
     HashMap::from([
@@ -34,19 +54,15 @@ fn get_key_pad() -> HashMap<u8, GridPoint> {
     ])
 }
 
-fn run(input_file: &Path) {
+pub fn part1(lines: &[Code]) -> usize {
     // Preamble
     let mut codes = Vec::with_capacity(5);
     let num_pad = get_num_pad();
     let key_pad = get_key_pad();
 
     // Parse
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    for line in reader.lines() {
-        let line = line.unwrap().trim().to_string();
-        let bytes = line.as_bytes();
+    for line in lines {
+        let bytes = line.keys.as_bytes();
         codes.push(vec![bytes[0], bytes[1], bytes[2], bytes[3]]);
     }
 
@@ -84,32 +100,28 @@ fn run(input_file: &Path) {
             third_from = to
         }
 
-        print_sequence(&third_sequence);
-        println!();
-        println!("Sequence Length {}", third_sequence.len());
+        // print_sequence(&third_sequence);
+        // println!();
+        // println!("Sequence Length {}", third_sequence.len());
         results.push(third_sequence.len());
     }
 
     // Result
     let mut result = 0;
 
-    for (i, code) in codes.iter().enumerate() {
-        let number = (code[0] as char).to_digit(10).unwrap() * 100
-            + (code[1] as char).to_digit(10).unwrap() * 10
-            + (code[2] as char).to_digit(10).unwrap();
-
-        result += results[i] * number as usize;
+    for (i, line) in lines.iter().enumerate() {
+        result += results[i] * line.number;
     }
 
-    println!("Result of part 1 is {}", result);
+    result
 }
 
-fn print_sequence(sequence: &Vec<u8>) {
-    for c in sequence {
-        print!("{}", *c as char);
-    }
-    // println!()
-}
+// fn print_sequence(sequence: &Vec<u8>) {
+//     for c in sequence {
+//         print!("{}", *c as char);
+//     }
+//     // println!()
+// }
 
 fn create_sequence(from: u8, to: u8, map: &HashMap<u8, GridPoint>) -> Vec<u8> {
     let mut rtn = Vec::new();
@@ -160,33 +172,3 @@ fn create_sequence(from: u8, to: u8, map: &HashMap<u8, GridPoint>) -> Vec<u8> {
 
     rtn
 }
-
-fn run2(_input_file: &Path) {}
-
-fn main() {
-    let input_file = get_input_path(env!("CARGO_MANIFEST_DIR"));
-
-    println!("Running {}", env!("CARGO_PKG_NAME"));
-    println!("InputFile: {}", input_file.display());
-
-    run(input_file.as_path());
-    run2(input_file.as_path());
-}
-
-#[cfg(test)]
-mod main_test {
-    use utils::get_test_input_path;
-
-    use crate::run;
-    use crate::run2;
-
-    #[test]
-    fn test_input_part_1() {
-        run(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-    }
-
-    #[test]
-    fn test_input_part_2() {
-        run2(&get_test_input_path(env!("CARGO_MANIFEST_DIR")));
-    }
-}
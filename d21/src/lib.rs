@@ -0,0 +1,251 @@
+use utils::error::{input_lines, AocError, InputLine};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod greedy;
+
+/// A door code like `029A`, three digits followed by `A`.
+pub struct Code {
+    pub keys: String,
+    /// The numeric part of the code.
+    pub number: usize,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input_lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| parse_code(&line))
+            .collect()
+    }
+
+    fn part1(codes: &Self::Input) -> Self::Answer1 {
+        solve::<2>(codes)
+    }
+
+    fn part2(codes: &Self::Input) -> Self::Answer2 {
+        solve::<25>(codes)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day21>),
+        ("greedy", solve_timed::<greedy::Day21Greedy>),
+    ]
+}
+
+fn parse_code(line: &InputLine) -> Result<Code, AocError> {
+    let keys = line.text.trim();
+    let digits = keys
+        .strip_suffix('A')
+        .filter(|digits| digits.len() == 3 && digits.bytes().all(|b| b.is_ascii_digit()))
+        .ok_or_else(|| line.error(keys, "expected three digits followed by A"))?;
+
+    Ok(Code {
+        keys: keys.to_string(),
+        number: line.parse(digits)?,
+    })
+}
+
+pub fn solve<const ROBOT_KEYPADS: usize>(codes: &[Code]) -> usize {
+    // Preamble
+    let mut paths = Vec::new();
+    let directional_key_costs = calc_directional_key_costs::<ROBOT_KEYPADS>();
+
+    // Solve
+    let mut results = Vec::with_capacity(codes.len());
+    for code in codes {
+        let mut results_per_char = 0;
+        let mut pos = NUMERIC_KEY_POSITIONS.len() - 1;
+        for c in code.keys.chars() {
+            // Digits are keys 0 to 9, the only other key is A.
+            let new_pos = c.to_digit(10).map_or(10, |digit| digit as usize);
+            get_paths::<3>(&mut paths, &NUMERIC_KEY_POSITIONS, pos, new_pos);
+            let cost: usize = paths
+                .iter()
+                .map(|path| {
+                    let mut pos = DirectionKey::Activate;
+                    path.iter()
+                        .map(|&new_pos| {
+                            let cost = directional_key_costs[pos as usize * 5 + new_pos as usize];
+                            pos = new_pos;
+                            cost
+                        })
+                        .sum()
+                })
+                .min()
+                .unwrap();
+            pos = new_pos;
+            paths.clear();
+            results_per_char += cost;
+        }
+        results.push(results_per_char);
+    }
+
+    // Result
+    let mut result = 0;
+
+    for (i, code) in codes.iter().enumerate() {
+        result += results[i] * code.number;
+    }
+
+    result
+}
+
+const NUMERIC_KEY_POSITIONS: [[usize; 2]; 11] = [
+    [1, 3],
+    [0, 2],
+    [1, 2],
+    [2, 2],
+    [0, 1],
+    [1, 1],
+    [2, 1],
+    [0, 0],
+    [1, 0],
+    [2, 0],
+    [2, 3],
+];
+
+#[derive(Debug, Clone, Copy)]
+enum DirectionKey {
+    Up = 0,
+    Activate = 1,
+    Left = 2,
+    Down = 3,
+    Right = 4,
+}
+
+fn get_paths<const HOLE_Y: usize>(
+    paths: &mut Vec<Vec<DirectionKey>>,
+    key_positions: &[[usize; 2]],
+    start: usize,
+    end: usize,
+) {
+    let [start_x, start_y] = key_positions[start];
+    let [end_x, end_y] = key_positions[end];
+
+    if !(start_x == 0 && end_y == HOLE_Y) {
+        // Start by going vertically and then horizontally
+        // This must not be done if we start on the left button and go to the top row, as that would make us pass
+        // over an empty space.
+        let mut path = Vec::new();
+        if start_y < end_y {
+            path.extend((start_y..end_y).map(|_| DirectionKey::Down));
+        } else if start_y > end_y {
+            path.extend((end_y..start_y).map(|_| DirectionKey::Up));
+        }
+        if start_x < end_x {
+            path.extend((start_x..end_x).map(|_| DirectionKey::Right));
+        } else if start_x > end_x {
+            path.extend((end_x..start_x).map(|_| DirectionKey::Left));
+        }
+        // We always need to end with Activate, so we actually press the button we go to.
+        path.push(DirectionKey::Activate);
+        paths.push(path);
+    }
+
+    if start_x != end_x && start_y != end_y && !(start_y == HOLE_Y && end_x == 0) {
+        // If we need to both vertically and horizontally, we can also do it by going horizontally first.
+        // This must not be done if we end on the left button, as that would make us pass over an empty space.
+        let mut path = Vec::new();
+        if start_x < end_x {
+            path.extend((start_x..end_x).map(|_| DirectionKey::Right));
+        } else if start_x > end_x {
+            path.extend((end_x..start_x).map(|_| DirectionKey::Left));
+        }
+        if start_y < end_y {
+            path.extend((start_y..end_y).map(|_| DirectionKey::Down));
+        } else if start_y > end_y {
+            path.extend((end_y..start_y).map(|_| DirectionKey::Up));
+        }
+        // We always need to end with Activate, so we actually press the button we go to.
+        path.push(DirectionKey::Activate);
+        paths.push(path);
+    }
+
+    // It is never worth zigzagging, as such paths can be reduced into a non-zigzagging path just by duplicating
+    // some presses while eliminating others, to get a path that takes fewer presses in total.
+}
+
+// Where each key is located on the directional keypad
+const DIRECTION_KEY_POSITIONS: [[usize; 2]; 5] = [[1, 0], [2, 0], [0, 1], [1, 1], [2, 1]];
+
+pub fn calc_directional_key_costs<const ROBOT_KEYPADS: usize>() -> Vec<usize> {
+    // Possible button inputs required to get the robot at the next level to press any button from any starting position
+    let direction_key_paths: Vec<_> = (0..(5 * 5))
+        .map(|i| {
+            let mut paths = Vec::new();
+            let start = i / 5;
+            let end = i % 5;
+            get_paths::<0>(&mut paths, &DIRECTION_KEY_POSITIONS, start, end);
+            paths
+        })
+        .collect();
+
+    // How many button presses it takes to get to any button from any other button and then press it
+    let mut path_costs: Vec<usize> = direction_key_paths
+        .iter()
+        .map(|paths| paths.iter().map(|path| path.len()).min().unwrap())
+        .collect();
+
+    for _ in 0..ROBOT_KEYPADS - 1 {
+        path_costs = calc_level_costs(&path_costs, &direction_key_paths);
+    }
+
+    path_costs
+}
+
+fn calc_level_costs(previous_costs: &[usize], paths: &[Vec<Vec<DirectionKey>>]) -> Vec<usize> {
+    paths
+        .iter()
+        .map(|paths| {
+            paths
+                .iter()
+                .map(|path| {
+                    // Sum up the costs of going from each button to the next one and pressing it, starting from Activate
+                    let mut pos = DirectionKey::Activate;
+                    path.iter()
+                        .map(|&new_pos| {
+                            let cost = previous_costs[pos as usize * 5 + new_pos as usize];
+                            pos = new_pos;
+                            cost
+                        })
+                        .sum()
+                })
+                .min()
+                .unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day21};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day21>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day21>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use fxhash::FxHashMap;
+use rayon::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::ops::{AddAssign, BitXor};
+use std::str::FromStr;
+use utils::error::{input_lines, unexpected_end, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod sequential;
+
+/// Secret numbers are pruned to 24 bits after every step.
+const PRUNE: usize = 16777216;
+
+#[derive(Debug, Copy, Clone)]
+pub struct SecretNumber(pub usize);
+
+impl FromStr for SecretNumber {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl Display for SecretNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl SecretNumber {
+    pub(crate) fn next_bananas_and_div(&mut self) -> (i8, i8) {
+        let bananas = self.bananas();
+        self.next();
+        let next_bananas = self.bananas();
+        (next_bananas, next_bananas - bananas)
+    }
+
+    pub(crate) fn bananas(&self) -> i8 {
+        (self.0 % 10) as i8
+    }
+
+    fn next(&mut self) {
+        let s1 = self.0 * 64;
+        let s1m = self.0.bitxor(s1);
+        let s1mo = s1m % PRUNE;
+        let s2 = s1mo / 32;
+        let s2m = s1mo.bitxor(s2);
+        let s2mo = s2m % PRUNE;
+        let s3 = s2mo * 2048;
+        let s3m = s2mo.bitxor(s3);
+        let s3mo = s3m % PRUNE;
+        self.0 = s3mo
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<SecretNumber>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut secrets = Vec::new();
+        for line in input_lines(input) {
+            let text = line.text.trim();
+            let secret: SecretNumber = line.parse(text)?;
+            // Bigger numbers would overflow while they are mixed, the puzzle keeps them pruned.
+            if secret.0 >= PRUNE {
+                return Err(line.error(text, format!("expected a secret number below {PRUNE}")));
+            }
+            secrets.push(secret);
+        }
+
+        if secrets.is_empty() {
+            return Err(unexpected_end(input, "secret numbers"));
+        }
+        Ok(secrets)
+    }
+
+    fn part1(values: &Self::Input) -> Self::Answer1 {
+        part1(values)
+    }
+
+    fn part2(values: &Self::Input) -> Self::Answer2 {
+        part2(values)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day22>),
+        ("sequential", solve_timed::<sequential::Day22Sequential>),
+    ]
+}
+
+pub fn part1(values: &[SecretNumber]) -> usize {
+    // Preamble
+    let mut values = values.to_vec();
+
+    // Solve
+    let result: usize = values
+        .par_iter_mut()
+        .map(|value| {
+            for _ in 0..2000 {
+                value.next();
+            }
+            value.0
+        })
+        .sum();
+    // Result
+    result
+}
+
+struct RollingContainer {
+    position: usize,
+    data: [i8; 4],
+}
+
+impl RollingContainer {
+    fn insert(&mut self, number: i8) {
+        self.data[self.position] = number;
+        if self.position == 3 {
+            self.position = 0
+        } else {
+            self.position += 1;
+        }
+    }
+
+    fn get_data(&self) -> [i8; 4] {
+        let mut rtn = [i8::MAX, i8::MAX, i8::MAX, i8::MAX];
+        for (i, item) in rtn.iter_mut().enumerate() {
+            let pos = (self.position + i) % 4;
+            *item = self.data[pos];
+        }
+        rtn
+    }
+}
+
+pub fn part2(values: &[SecretNumber]) -> usize {
+    // Preamble
+    let mut values = values.to_vec();
+
+    // Solve
+    let outer_results = values
+        .par_iter_mut()
+        .map(|value| {
+            let mut last_four_div = RollingContainer {
+                position: 0,
+                data: [i8::MAX, i8::MAX, i8::MAX, i8::MAX],
+            };
+
+            let mut last_four_bananas = RollingContainer {
+                position: 0,
+                data: [i8::MAX, i8::MAX, i8::MAX, i8::MAX],
+            };
+
+            let mut inner_results: FxHashMap<[i8; 4], usize> = FxHashMap::default();
+            last_four_div.insert(0);
+            last_four_bananas.insert(value.bananas());
+            for i in 0..2000 {
+                let (bananas, div) = value.next_bananas_and_div();
+                last_four_div.insert(div);
+                last_four_bananas.insert(bananas);
+
+                if i > 3 {
+                    let key = last_four_div.get_data();
+                    inner_results.entry(key).or_insert(bananas as usize);
+                }
+            }
+            inner_results
+        })
+        .reduce(FxHashMap::default, |mut outer_results, inner_results| {
+            inner_results.iter().for_each(|(key, &value)| {
+                outer_results.entry(*key).or_default().add_assign(value);
+            });
+            outer_results
+        });
+
+    // Result
+    // No buyers, no bananas.
+    let bananas = outer_results.values().copied().max().unwrap_or(0);
+
+    // println!("Best sequence {:?} with {} bananas", sequence, bananas);
+    bananas
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day22};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day22>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day22>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap(),
+        );
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap(),
+        );
+    }
+}
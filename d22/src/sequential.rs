@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
+use utils::error::AocError;
+use utils::solution::Solution;
+
+use crate::{Day22, RollingContainer, SecretNumber};
+
+/// Simulates the buyers one after the other on a single thread.
+pub struct Day22Sequential;
+
+impl Solution for Day22Sequential {
+    type Input = Vec<SecretNumber>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day22::parse(input)
+    }
+
+    fn part1(values: &Self::Input) -> Self::Answer1 {
+        part1(values)
+    }
+
+    fn part2(values: &Self::Input) -> Self::Answer2 {
+        part2(values)
+    }
+}
+
+pub fn part1(values: &[SecretNumber]) -> usize {
+    // Preamble
+    let mut result = 0;
+
+    // Solve
+    for &value in values {
+        let mut value = value;
+        // println!("Start number: {}", &value);
+        for _ in 0..2000 {
+            value.next();
+        }
+        result += value.0;
+        // println!("After 2000 iterations {}", &value);
+    }
+    // Result
+    result
+}
+
+pub fn part2(values: &[SecretNumber]) -> usize {
+    // Preamble
+    let mut outer_results: HashMap<[i8; 4], usize> = HashMap::new();
+    // let mut inner_result_list = Vec::with_capacity(values.len());
+
+    // Solve
+    for &value in values {
+        let mut value = value;
+
+        let mut last_four_div = RollingContainer {
+            position: 0,
+            data: [i8::MAX, i8::MAX, i8::MAX, i8::MAX],
+        };
+
+        let mut last_four_bananas = RollingContainer {
+            position: 0,
+            data: [i8::MAX, i8::MAX, i8::MAX, i8::MAX],
+        };
+
+        let mut inner_results: HashMap<[i8; 4], i8> = HashMap::new();
+        // println!("Start number: {}", &value);
+        last_four_div.insert(0);
+        last_four_bananas.insert(value.bananas());
+        for i in 0..2000 {
+            // if [0, 0, 0, 1] == last_four_div.get_data() {
+            //     println!("After {i} in {value}");
+            //     println!("Bananas: {:?}", last_four_bananas.get_data());
+            //     println!("Div    : {:?}", last_four_div.get_data());
+            // }
+            let (bananas, div) = value.next_bananas_and_div();
+            last_four_div.insert(div);
+            last_four_bananas.insert(bananas);
+
+            if i > 3 {
+                // let entry = inner_results
+                //     .entry(last_four_div.get_data())
+                //     .or_insert(bananas as usize);
+                // if *entry < bananas as usize {
+                //     *entry = bananas as usize;
+                // }
+                let key = last_four_div.get_data();
+                inner_results.entry(key).or_insert(bananas);
+            }
+        }
+        inner_results.iter().for_each(|(key, &value)| {
+            outer_results
+                .entry(*key)
+                .or_default()
+                .add_assign(value as usize);
+        });
+        // inner_result_list.push(inner_results);
+    }
+    // Result
+    // let mut tmp: Vec<(&[i8; 4], usize)> = outer_results
+    //     .iter()
+    //     .map(|(key, &value)| (key, value))
+    //     .collect();
+    //
+    // tmp.sort_by(|v, v2| v2.1.cmp(&v.1));
+    //
+    // for i in 0..10 {
+    //     println!("{:?}", tmp[i]);
+    // }
+
+    // No buyers, no bananas.
+    let bananas = outer_results.values().copied().max().unwrap_or(0);
+    // println!("Best sequence {:?} with {}", sequence, bananas);
+
+    // let mut best = 0;
+    // for a in -9..10 {
+    //     for b in -9..10 {
+    //         for c in -9..10 {
+    //             for d in -9..10 {
+    //                 let mut current = 0;
+    //                 for results in &inner_result_list{
+    //                     if let Some(&value ) = results.get(&[a,b,c,d]) {
+    //                         current += value;
+    //                     }
+    //                 }
+    //                 best = best.max(current);
+    //             }
+    //         }
+    //     }
+    // }
+    // println!("Other result for 2 {best}");
+
+    bananas
+}
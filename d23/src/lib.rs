@@ -0,0 +1,201 @@
+use fxhash::{FxHashMap, FxHashSet};
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use utils::error::{input_lines, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<(String, String)>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut connections = Vec::new();
+
+        for line in input_lines(input) {
+            let mut split = line.text.trim().split("-");
+
+            let (left, right) = (
+                line.next(&mut split, "left computer")?,
+                line.next(&mut split, "right computer")?,
+            );
+            connections.push((left.to_string(), right.to_string()));
+        }
+
+        Ok(connections)
+    }
+
+    fn part1(connections: &Self::Input) -> Self::Answer1 {
+        part1(connections)
+    }
+
+    fn part2(connections: &Self::Input) -> Self::Answer2 {
+        part2(connections)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day23>)]
+}
+
+pub fn part1(connections: &[(String, String)]) -> usize {
+    // Preamble
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+
+    // Prepare
+    for (left, right) in connections {
+        graph
+            .entry(left.to_string())
+            .or_default()
+            .push(right.to_string());
+        graph
+            .entry(right.to_string())
+            .or_default()
+            .push(left.to_string());
+    }
+
+    // Solve
+    let mut seen = HashSet::new();
+    let mut results = Vec::new();
+    for (key1, value1) in &graph {
+        for key2 in value1 {
+            let value2 = graph.get(key2).unwrap();
+            for key3 in value2 {
+                let hash = create_hash(key1, key2, key3);
+                if !seen.insert(hash) {
+                    continue;
+                }
+
+                let value3 = graph.get(key3).unwrap();
+                if value3.contains(key1) {
+                    let tmp = vec![key1, key2, key3];
+                    // tmp.sort();
+                    results.push(tmp);
+                }
+            }
+        }
+    }
+
+    // Result
+    let mut result = 0;
+
+    // results.sort();
+    for r in results {
+        // println!("{},{},{}", r[0], r[1], r[2]);
+        if r[0].as_bytes()[0] == b't' || r[1].as_bytes()[0] == b't' || r[2].as_bytes()[0] == b't' {
+            result += 1;
+        }
+    }
+
+    result
+}
+
+struct SearchContext<'a> {
+    position: &'a str,
+    check_for_keys: Vec<&'a str>,
+}
+
+pub fn part2(connections: &[(String, String)]) -> String {
+    // Preamble
+    let mut graph: FxHashMap<String, FxHashSet<String>> = FxHashMap::default();
+
+    // Prepare
+    for (left, right) in connections {
+        graph
+            .entry(left.to_string())
+            .or_default()
+            .insert(right.to_string());
+        graph
+            .entry(right.to_string())
+            .or_default()
+            .insert(left.to_string());
+    }
+
+    // Solve
+    let mut seen = FxHashSet::default();
+    let mut best_network = Vec::new();
+    for key1 in graph.keys() {
+        let mut queue = vec![SearchContext {
+            position: key1,
+            check_for_keys: vec![],
+        }];
+
+        'outer: while let Some(item) = queue.pop() {
+            let mut network = item.check_for_keys.clone();
+            network.push(item.position);
+            network.sort();
+
+            let hash = create_hash2(&network);
+            if !seen.insert(hash) {
+                continue 'outer;
+            }
+
+            let values = graph.get(item.position).unwrap();
+            if values.len() < best_network.len() {
+                continue 'outer;
+            }
+
+            for &check_for_key in &item.check_for_keys {
+                if !values.contains(check_for_key) {
+                    continue 'outer;
+                }
+            }
+
+            for value in values {
+                queue.push(SearchContext {
+                    position: value,
+                    check_for_keys: network.clone(),
+                })
+            }
+
+            if network.len() > best_network.len() {
+                best_network = network;
+            }
+        }
+    }
+
+    best_network.join(",")
+}
+
+fn create_hash(key1: &str, key2: &str, key3: &str) -> u64 {
+    let mut items = vec![key1, key2, key3];
+    items.sort();
+    let mut hasher = DefaultHasher::new();
+    items.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn create_hash2(keys: &[&str]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    keys.hash(&mut hasher);
+    hasher.finish()
+}
+
+// fn values_contains_key(values: &Vec<String>, key: &str) -> bool {
+//     for value in values {
+//         if value == key {
+//             return true;
+//         }
+//     }
+//     false
+// }
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day23;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day23>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day23>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,490 @@
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitOr, BitXor};
+use std::str::FromStr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Relaxed;
+use utils::error::{input_lines, AocError};
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub mod sequential;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GateOperation {
+    Or,
+    And,
+    Xor,
+}
+
+impl Display for GateOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let to_write = match self {
+            GateOperation::Or => "OR",
+            GateOperation::And => "AND",
+            GateOperation::Xor => "XOR",
+        };
+        write!(f, "{to_write}")
+    }
+}
+
+impl FromStr for GateOperation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "XOR" => Ok(GateOperation::Xor),
+            "OR" => Ok(GateOperation::Or),
+            "AND" => Ok(GateOperation::And),
+            _ => Err(String::from("expected AND, OR or XOR")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Gate {
+    pub left: String,
+    pub right: String,
+    pub output: String,
+    pub operation: GateOperation,
+}
+
+impl Display for Gate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} -> {}",
+            self.left, self.operation, self.right, self.output
+        )
+    }
+}
+
+pub struct Device {
+    pub value_map: HashMap<String, bool>,
+    pub gates: Vec<Gate>,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Device;
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut value_map = HashMap::new();
+        let mut gates = Vec::new();
+
+        let mut is_init_values = true;
+        for line in input_lines(input) {
+            let text = line.text.trim();
+
+            if text.is_empty() {
+                is_init_values = false;
+                continue;
+            }
+
+            if is_init_values {
+                let mut split = text.split(":").map(|s| s.trim());
+                let wire = line.next(&mut split, "wire")?.to_string();
+                let wire_value = match line.next(&mut split, "wire value")? {
+                    "0" => false,
+                    "1" => true,
+                    value => return Err(line.error(value, "expected 0 or 1")),
+                };
+                value_map.insert(wire, wire_value);
+            } else {
+                let mut split = text.split_ascii_whitespace();
+                let left = line.next(&mut split, "left wire")?.to_string();
+                let operation = line.parse_next(&mut split, "operation")?;
+                let right = line.next(&mut split, "right wire")?.to_string();
+                let output = line.next(&mut split.skip(1), "output wire")?.to_string();
+                gates.push(Gate {
+                    left,
+                    right,
+                    output,
+                    operation,
+                })
+            }
+        }
+
+        Ok(Device { value_map, gates })
+    }
+
+    fn part1(device: &Self::Input) -> Self::Answer1 {
+        part1(device)
+    }
+
+    fn part2(device: &Self::Input) -> Self::Answer2 {
+        part2(device)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        ("main", solve_timed::<Day24>),
+        ("sequential", solve_timed::<sequential::Day24Sequential>),
+    ]
+}
+
+pub fn part1(device: &Device) -> u64 {
+    // Preamble
+    let mut value_map = device.value_map.clone();
+    let gates = &device.gates;
+
+    // Solve
+    let mut solved_gates = HashSet::with_capacity(gates.len());
+    loop {
+        if solved_gates.len() == gates.len() {
+            break;
+        }
+
+        for (i, gate) in gates.iter().enumerate() {
+            if solved_gates.contains(&i) {
+                continue;
+            }
+
+            let result = solve_gate(gate, &value_map);
+
+            if let Some(r) = result {
+                value_map.insert(gate.output.clone(), r);
+                solved_gates.insert(i);
+            }
+        }
+    }
+
+    // Result
+    calculate_result(value_map)
+}
+
+pub fn part2(device: &Device) -> String {
+    // Preamble
+    let mut gates = device.gates.clone();
+
+    let outputs: Vec<String> = gates.iter().map(|g| g.output.clone()).collect();
+
+    let mut z_len = 0;
+
+    let mut output_map = HashMap::new();
+
+    for (i, gate) in gates.iter().enumerate() {
+        if gate.output.as_bytes()[0] == b'z' {
+            z_len += 1;
+        }
+        output_map.insert(gate.output.clone(), i);
+    }
+
+    let swapped_outputs = find_z_anomalies(&mut output_map, &mut gates, z_len);
+
+    let checks = checked_sums(&device.value_map);
+
+    // 'outer: for i1 in 0..outputs.len() {
+    //     for i2 in i1 + 1..outputs.len() {
+    //         let o1 = &outputs[i1];
+    //         let o2 = &outputs[i2];
+    //
+    //         let mut overrides = HashMap::with_capacity(2);
+    //         overrides.insert(o1, o2);
+    //         overrides.insert(o2, o1);
+    //
+    //         let result = solve_gates(&value_map, &gates, overrides);
+    //
+    //         if result == should_be {
+    //             swapped_outputs.push((o1.to_string(), o2.to_string()));
+    //             break 'outer;
+    //         }
+    //     }
+    // }
+
+    let r1 = AtomicUsize::default();
+    let r2 = AtomicUsize::default();
+
+    (0..outputs.len()).into_par_iter().any(|i1| {
+        (i1 + 1..outputs.len()).any(|i2| {
+            let o1 = &outputs[i1];
+            let o2 = &outputs[i2];
+
+            let mut overrides = HashMap::with_capacity(2);
+            overrides.insert(o1, o2);
+            overrides.insert(o2, o1);
+
+            if adds_correctly(&checks, &gates, &overrides) {
+                r1.store(i1, Relaxed);
+                r2.store(i2, Relaxed);
+                return true;
+            }
+            false
+        })
+    });
+
+    // Result
+    let mut all_wires = Vec::with_capacity(swapped_outputs.len() * 2 + 2);
+    for (l, r) in swapped_outputs {
+        all_wires.push(l);
+        all_wires.push(r);
+    }
+
+    let o1 = &outputs[r1.load(Relaxed)];
+    let o2 = &outputs[r2.load(Relaxed)];
+    all_wires.push(o1.to_string());
+    all_wires.push(o2.to_string());
+    all_wires.sort();
+
+    all_wires.join(",")
+}
+
+/// Numbers the repaired device has to add. A single pair can come out right by chance with the
+/// wrong outputs swapped, so every candidate has to add all of them.
+const CHECKED_SUMS: [(u64, u64); 8] = [
+    (28872341726885, 28414614475596),
+    (0x1fff_ffff_ffff, 0),
+    (0x1fff_ffff_ffff, 1),
+    (0x1fff_ffff_ffff, 0x1fff_ffff_ffff),
+    (0x1555_5555_5555, 0x1555_5555_5555),
+    (0x0aaa_aaaa_aaaa, 0x0aaa_aaaa_aaaa),
+    (0x1555_5555_5555, 0x0aaa_aaaa_aaaa),
+    (0x0aaa_aaaa_aaaa, 0x1fff_ffff_ffff),
+];
+
+/// The wire values of every pair in [CHECKED_SUMS] together with the sum the device should put
+/// out. Bits beyond the inputs of the device are left out.
+pub(crate) fn checked_sums(value_map: &HashMap<String, bool>) -> Vec<(HashMap<String, bool>, u64)> {
+    CHECKED_SUMS
+        .iter()
+        .map(|&(x, y)| {
+            let mut value_map = value_map.clone();
+            set_x_to_gates(&mut value_map, x);
+            set_y_to_gates(&mut value_map, y);
+            let should_be = calculate_x(&value_map) + calculate_y(&value_map);
+            (value_map, should_be)
+        })
+        .collect()
+}
+
+/// Whether `gates` with the outputs in `overrides` swapped add up all `checks`, see
+/// [checked_sums].
+pub(crate) fn adds_correctly(
+    checks: &[(HashMap<String, bool>, u64)],
+    gates: &[Gate],
+    overrides: &HashMap<&String, &String>,
+) -> bool {
+    checks.iter().all(|(value_map, should_be)| {
+        solve_gates(value_map, gates, overrides.clone()) == *should_be
+    })
+}
+
+fn set_x_to_gates(value_map: &mut HashMap<String, bool>, mut number: u64) {
+    for i in 0..64 {
+        let key = format!("x{i:<02}");
+        if let Some(i) = value_map.get_mut(&key) {
+            *i = number % 2 == 1;
+        }
+        number /= 2;
+    }
+}
+
+fn set_y_to_gates(value_map: &mut HashMap<String, bool>, mut number: u64) {
+    for i in 0..64 {
+        let key = format!("y{i:<02}");
+        if let Some(i) = value_map.get_mut(&key) {
+            *i = number % 2 == 1;
+        }
+        number /= 2;
+    }
+}
+
+pub fn solve_gates(
+    init_value_map: &HashMap<String, bool>,
+    gates: &[Gate],
+    overrides: HashMap<&String, &String>,
+) -> u64 {
+    let mut value_map = init_value_map.clone();
+    let mut solved_gates = HashSet::with_capacity(gates.len());
+    let mut last_solved_gates_amount = usize::MAX;
+    loop {
+        if solved_gates.len() == gates.len() {
+            break;
+        }
+
+        if solved_gates.len() == last_solved_gates_amount {
+            break;
+        }
+
+        last_solved_gates_amount = solved_gates.len();
+
+        for (i, gate) in gates.iter().enumerate() {
+            if solved_gates.contains(&i) {
+                continue;
+            }
+
+            let result = solve_gate(gate, &value_map);
+
+            if let Some(r) = result {
+                let output = overrides
+                    .get(&gate.output)
+                    .map_or(gate.output.as_str(), |v| v);
+                value_map.insert(output.to_string(), r);
+                solved_gates.insert(i);
+            }
+        }
+    }
+
+    calculate_result(value_map)
+}
+
+fn find_z_anomalies(
+    output_map: &mut HashMap<String, usize>,
+    gates: &mut [Gate],
+    z_len: usize,
+) -> Vec<(String, String)> {
+    let mut rtn = Vec::new();
+    for z in 0..z_len - 1 {
+        let key = format!("z{z:<02}");
+        let g_id = *output_map.get(&key).unwrap();
+        let gate = gates.get(g_id).unwrap();
+        if gate.operation == GateOperation::Xor {
+            continue;
+        }
+        // println!("Bad gate: {}", gate);
+        let expected_nbr_dependents = z * 6;
+        let (s, swap_gate) = find_swap_gate(expected_nbr_dependents, output_map, gates);
+        // println!("Swap with {swap_gate}");
+        rtn.push((gate.output.clone(), swap_gate.output.clone()));
+        swap_gates(gates, g_id, s);
+        // Later bits depend on these wires, so they have to lead to the repaired gates.
+        output_map.insert(gates[g_id].output.clone(), g_id);
+        output_map.insert(gates[s].output.clone(), s);
+    }
+    rtn
+}
+
+fn swap_gates(gates: &mut [Gate], start: usize, end: usize) {
+    let [g1, g2] = gates.get_disjoint_mut([start, end]).unwrap();
+    let tmp = g1.output.clone();
+    g1.output = g2.output.clone();
+    g2.output = tmp;
+}
+
+fn find_swap_gate<'a>(
+    expected_nbr_dependents: usize,
+    output_map: &'a HashMap<String, usize>,
+    gates: &'a [Gate],
+) -> (usize, &'a Gate) {
+    for (g, gate) in gates.iter().enumerate() {
+        if gate.operation == GateOperation::Xor {
+            let dependents_number = dependents_of_wire(&gate.output, output_map, gates);
+            // println!("{dependents_number} == {expected_nbr_dependents}");
+            if dependents_number == expected_nbr_dependents {
+                return (g, gate);
+            }
+        }
+    }
+    panic!()
+}
+
+fn dependents_of_wire(
+    output: &String,
+    output_map: &HashMap<String, usize>,
+    gates: &[Gate],
+) -> usize {
+    let mut rtn = HashSet::new();
+
+    let mut queue = vec![output];
+
+    while let Some(item) = queue.pop() {
+        if item.chars().next().is_some_and(|c| c == 'y' || c == 'x') {
+            continue;
+        }
+
+        let gate = gates.get(*output_map.get(item).unwrap()).unwrap();
+        if rtn.insert(gate.left.clone()) {
+            queue.push(&gate.left);
+        }
+        if rtn.insert(gate.right.clone()) {
+            queue.push(&gate.right);
+        }
+    }
+
+    rtn.len()
+}
+
+pub fn solve_gate(gate: &Gate, value_map: &HashMap<String, bool>) -> Option<bool> {
+    let left = value_map.get(&gate.left)?;
+    let right = value_map.get(&gate.right)?;
+
+    let rtn = match gate.operation {
+        GateOperation::Or => left.bitor(right),
+        GateOperation::And => left.bitand(right),
+        GateOperation::Xor => left.bitxor(right),
+    };
+
+    Some(rtn)
+}
+
+fn calculate_y(value_map: &HashMap<String, bool>) -> u64 {
+    let mut rtn = 0;
+    for i in (0..64).rev() {
+        let key = format!("y{i:<02}");
+        rtn <<= 1;
+        if *value_map.get(&key).unwrap_or(&false) {
+            rtn += 1;
+        }
+    }
+    rtn
+}
+
+fn calculate_x(value_map: &HashMap<String, bool>) -> u64 {
+    let mut rtn = 0;
+    for i in (0..64).rev() {
+        let key = format!("x{i:<02}");
+        rtn <<= 1;
+        if *value_map.get(&key).unwrap_or(&false) {
+            rtn += 1;
+        }
+    }
+    rtn
+}
+
+pub fn calculate_result(value_map: HashMap<String, bool>) -> u64 {
+    let mut rtn = 0;
+    for i in (0..64).rev() {
+        let key = format!("z{i:<02}");
+        rtn <<= 1;
+        if *value_map.get(&key).unwrap_or(&false) {
+            rtn += 1;
+        }
+    }
+    rtn
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2, assert_variants_agree};
+
+    use crate::{variants, Day24};
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day24>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    #[ignore = "the example of part 2 ANDs the bits instead of adding them"]
+    fn test_input_part_2() {
+        assert_part2::<Day24>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_2").unwrap());
+    }
+
+    #[test]
+    fn test_input_3_part_2() {
+        assert_part2::<Day24>(&example_path(env!("CARGO_MANIFEST_DIR"), "example_3").unwrap());
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        assert_variants_agree(
+            &variants(),
+            &example_path(env!("CARGO_MANIFEST_DIR"), "example_3").unwrap(),
+        );
+    }
+}
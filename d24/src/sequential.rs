@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use utils::error::AocError;
+use utils::solution::Solution;
+
+use crate::{adds_correctly, checked_sums, find_z_anomalies, part1, Day24, Device};
+
+/// Searches the last swapped pair of outputs on a single thread.
+pub struct Day24Sequential;
+
+impl Solution for Day24Sequential {
+    type Input = Device;
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Day24::parse(input)
+    }
+
+    fn part1(device: &Self::Input) -> Self::Answer1 {
+        part1(device)
+    }
+
+    fn part2(device: &Self::Input) -> Self::Answer2 {
+        part2(device)
+    }
+}
+
+pub fn part2(device: &Device) -> String {
+    // Preamble
+    let mut gates = device.gates.clone();
+
+    let outputs: Vec<String> = gates.iter().map(|g| g.output.clone()).collect();
+
+    let mut z_len = 0;
+
+    let mut output_map = HashMap::new();
+
+    for (i, gate) in gates.iter().enumerate() {
+        if gate.output.as_bytes()[0] == b'z' {
+            z_len += 1;
+        }
+        output_map.insert(gate.output.clone(), i);
+    }
+
+    let mut swapped_outputs = find_z_anomalies(&mut output_map, &mut gates, z_len);
+
+    let checks = checked_sums(&device.value_map);
+
+    'outer: for i1 in 0..outputs.len() {
+        for i2 in i1 + 1..outputs.len() {
+            let o1 = &outputs[i1];
+            let o2 = &outputs[i2];
+
+            let mut overrides = HashMap::with_capacity(2);
+            overrides.insert(o1, o2);
+            overrides.insert(o2, o1);
+
+            if adds_correctly(&checks, &gates, &overrides) {
+                swapped_outputs.push((o1.to_string(), o2.to_string()));
+                break 'outer;
+            }
+        }
+    }
+
+    // Result
+    let mut all_wires = Vec::with_capacity(swapped_outputs.len() * 2);
+    for (l, r) in swapped_outputs {
+        all_wires.push(l);
+        all_wires.push(r);
+    }
+    all_wires.sort();
+
+    all_wires.join(",")
+}
@@ -0,0 +1,134 @@
+use utils::error::{input_lines, unexpected_end, AocError, InputLine};
+use utils::grid::Grid;
+use utils::grid_point::GridPoint;
+use utils::solution::{solve_timed, NoAnswer, Solution, Variant};
+
+/// Width and height of a lock or key schematic.
+const SCHEMATIC_WIDTH: usize = 5;
+const SCHEMATIC_HEIGHT: usize = 7;
+
+pub struct LocksAndKeys {
+    pub locks: Vec<[u8; 5]>,
+    pub keys: Vec<[u8; 5]>,
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = LocksAndKeys;
+    type Answer1 = usize;
+    type Answer2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(locks_and_keys: &Self::Input) -> Self::Answer1 {
+        part1(locks_and_keys)
+    }
+
+    fn part2(_locks_and_keys: &Self::Input) -> Self::Answer2 {
+        NoAnswer
+    }
+}
+
+fn parse(input: &str) -> Result<LocksAndKeys, AocError> {
+    // Preamble
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+    let mut rows = Vec::new();
+
+    // Parse
+    for line in input_lines(input) {
+        let text = line.text.trim();
+        if text.is_empty() {
+            if rows.is_empty() || rows.len() % SCHEMATIC_HEIGHT != 0 {
+                return Err(line.error(text, "expected a lock or a key, found an empty line"));
+            }
+            continue;
+        }
+        rows.push(parse_row(&line, text)?.to_vec());
+    }
+    if rows.len() % SCHEMATIC_HEIGHT != 0 {
+        return Err(unexpected_end(input, "rows of the last lock or key"));
+    }
+
+    let schematics = Grid::from_rows(rows)?;
+    for schematic in schematics.tiles(SCHEMATIC_WIDTH, SCHEMATIC_HEIGHT) {
+        // The full top or bottom row does not count into the height.
+        let heights: [u8; SCHEMATIC_WIDTH] = std::array::from_fn(|x| {
+            schematic.column(x).filter(|&&filled| filled).count() as u8 - 1
+        });
+        if schematic[GridPoint::new(0, 0)] {
+            locks.push(heights);
+        } else {
+            keys.push(heights);
+        }
+    }
+
+    Ok(LocksAndKeys { locks, keys })
+}
+
+/// Reads the five columns of a schematic row, `true` where the column is filled.
+fn parse_row(line: &InputLine, text: &str) -> Result<[bool; SCHEMATIC_WIDTH], AocError> {
+    let start = line.position_of(text).column;
+    let mut chars = text.chars();
+
+    let mut rtn = [false; SCHEMATIC_WIDTH];
+    for (i, filled) in rtn.iter_mut().enumerate() {
+        *filled = match chars.next() {
+            Some('#') => true,
+            Some('.') => false,
+            Some(found) => {
+                return Err(AocError::UnexpectedChar {
+                    found,
+                    position: Some(line.position(start + i)),
+                })
+            }
+            None => return Err(line.error(text, "expected a row of 5 columns")),
+        };
+    }
+
+    Ok(rtn)
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<Day25>)]
+}
+
+pub fn part1(locks_and_keys: &LocksAndKeys) -> usize {
+    // Solve
+    let mut result: usize = 0;
+    for lock in &locks_and_keys.locks {
+        'keys: for key in &locks_and_keys.keys {
+            for i in 0..5 {
+                if (lock[i] + key[i]) > 5 {
+                    continue 'keys;
+                }
+            }
+            result += 1;
+        }
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::Day25;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<Day25>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<Day25>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
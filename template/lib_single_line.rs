@@ -0,0 +1,56 @@
+use utils::error::AocError;
+use utils::solution::{solve_timed, Solution, Variant};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(line: &Self::Input) -> Self::Answer1 {
+        part1(line)
+    }
+
+    fn part2(line: &Self::Input) -> Self::Answer2 {
+        part2(line)
+    }
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![("main", solve_timed::<DayXX>)]
+}
+
+pub fn part1(_line: &str) -> usize {
+    // Preamble
+    let result: usize = 0;
+    // Solve
+    // Result
+    result
+}
+
+pub fn part2(_line: &str) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::inputs::example_path;
+    use utils::solution::{assert_part1, assert_part2};
+
+    use crate::DayXX;
+
+    #[test]
+    fn test_input_part_1() {
+        assert_part1::<DayXX>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        assert_part2::<DayXX>(&example_path(env!("CARGO_MANIFEST_DIR"), "example").unwrap());
+    }
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::Path;
+
+use crate::solution::Parameters;
+
+/// Name of the file next to the test inputs that holds their expected answers.
+///
+/// Every non-empty line that does not start with `#` has the form `<input file> <part> <answer>`,
+/// for example `input_test.txt 1 11`. Inputs that need other [Parameters] than the real inputs
+/// set them with lines of the form `<input file> <name> <value>`, like `input_test.txt width 11`.
+pub const ANSWERS_FILE_NAME: &str = "answers.txt";
+
+/// Looks up the expected answer of `part` for `input_file` in the answers file of its folder.
+pub fn get_expected_answer(input_file: &Path, part: u8) -> Option<String> {
+    entries(input_file)
+        .into_iter()
+        .find_map(|(key, value)| match key.parse::<u8>() {
+            Ok(answer_part) if answer_part == part => Some(value),
+            _ => None,
+        })
+}
+
+/// The [Parameters] set for `input_file` in the answers file of its folder, none if there is no
+/// answers file.
+pub fn get_parameters(input_file: &Path) -> Parameters {
+    let mut parameters = Parameters::default();
+    for (key, value) in entries(input_file) {
+        if key.parse::<u8>().is_err() {
+            parameters.set(&key, &value);
+        }
+    }
+    parameters
+}
+
+/// The `<key> <value>` pairs of the lines for `input_file` in the answers file of its folder.
+fn entries(input_file: &Path) -> Vec<(String, String)> {
+    let Some(answers_file) = input_file.parent().map(|dir| dir.join(ANSWERS_FILE_NAME)) else {
+        return Vec::new();
+    };
+    let (Some(input_file_name), Ok(content)) = (
+        input_file.file_name().and_then(|name| name.to_str()),
+        fs::read_to_string(answers_file),
+    ) else {
+        return Vec::new();
+    };
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut split = line.split_ascii_whitespace();
+            let file_name = split.next()?;
+            let key = split.next()?;
+            let value = split.next()?;
+            (file_name == input_file_name).then(|| (key.to_string(), value.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,138 @@
+use crate::math::UnsignedInteger;
+
+/// Number of decimal digits, 0 has one digit.
+pub fn digit_count<T: UnsignedInteger>(number: T) -> u32 {
+    if number == T::ZERO {
+        1
+    } else {
+        number.ilog10() + 1
+    }
+}
+
+/// Number of digits in `base`, 0 has one digit. Panics if `base` is below 2.
+pub fn digit_count_in_base<T: UnsignedInteger>(number: T, base: T) -> u32 {
+    if number == T::ZERO {
+        1
+    } else {
+        number.ilog(base) + 1
+    }
+}
+
+/// The digits of `number` in `base`, least significant first. 0 has the single digit 0.
+///
+/// Panics if `base` is below 2, which would divide by zero or never get smaller.
+pub fn digits<T: UnsignedInteger>(number: T, base: T) -> impl Iterator<Item = T> {
+    assert!(base > T::ONE, "base must be at least 2");
+    let mut rest = Some(number);
+    std::iter::from_fn(move || {
+        let current = rest?;
+        rest = (current >= base).then(|| current / base);
+        Some(current % base)
+    })
+}
+
+/// Builds a number out of its digits in `base`, most significant first. `None` if it does not
+/// fit into `T`.
+pub fn from_digits<T: UnsignedInteger>(digits: impl IntoIterator<Item = T>, base: T) -> Option<T> {
+    digits.into_iter().try_fold(T::ZERO, |rtn, digit| {
+        rtn.checked_mul(base)?.checked_add(digit)
+    })
+}
+
+/// Splits off the lowest `split_at` decimal digits, `1234` split at 1 is `(123, 4)`.
+pub fn split_number<T: UnsignedInteger>(number: T, split_at: u32) -> (T, T) {
+    let divisor = T::TEN.checked_pow(split_at);
+    match divisor {
+        Some(divisor) => (number / divisor, number % divisor),
+        None => (T::ZERO, number),
+    }
+}
+
+/// Writes `right` behind `left`, `12` and `345` become `12345`. `None` if the result does not
+/// fit into `T`.
+pub fn concatenate<T: UnsignedInteger>(left: T, right: T) -> Option<T> {
+    left.checked_mul(T::TEN.checked_pow(digit_count(right))?)?
+        .checked_add(right)
+}
+
+/// The reverse of [concatenate]: the part of `number` in front of `suffix`, `None` if `number`
+/// does not end with the digits of `suffix`. `12345` without `45` is `123`.
+pub fn deconcatenate<T: UnsignedInteger>(number: T, suffix: T) -> Option<T> {
+    let (prefix, rest) = split_number(number, digit_count(suffix));
+    (rest == suffix).then_some(prefix)
+}
+
+/// The decimal digits in reverse order, `1230` becomes `321`. `None` if the result does not fit
+/// into `T`.
+pub fn reverse_digits<T: UnsignedInteger>(number: T) -> Option<T> {
+    from_digits(digits(number, T::TEN), T::TEN)
+}
+
+/// Whether the decimal digits read the same in both directions.
+pub fn is_palindrome<T: UnsignedInteger>(number: T) -> bool {
+    is_palindrome_in_base(number, T::TEN)
+}
+
+pub fn is_palindrome_in_base<T: UnsignedInteger>(number: T, base: T) -> bool {
+    let digits: Vec<T> = digits(number, base).collect();
+    digits.iter().eq(digits.iter().rev())
+}
+
+#[cfg(test)]
+mod digits_test {
+    use crate::digits::{
+        concatenate, deconcatenate, digit_count, digit_count_in_base, digits, from_digits,
+        is_palindrome, is_palindrome_in_base, reverse_digits, split_number,
+    };
+
+    #[test]
+    fn test_counting_and_iterating() {
+        assert_eq!(digit_count(0u64), 1);
+        assert_eq!(digit_count(9u8), 1);
+        assert_eq!(digit_count(1000u32), 4);
+        assert_eq!(digit_count(u64::MAX), 20);
+        assert_eq!(digit_count_in_base(255u8, 2), 8);
+        assert_eq!(digit_count_in_base(256u16, 16), 3);
+
+        assert_eq!(digits(1203u32, 10).collect::<Vec<_>>(), [3, 0, 2, 1]);
+        assert_eq!(digits(0u32, 10).collect::<Vec<_>>(), [0]);
+        assert_eq!(digits(6u8, 2).collect::<Vec<_>>(), [0, 1, 1]);
+        assert_eq!(from_digits([1u8, 1, 0], 2), Some(6));
+        assert_eq!(from_digits([2u8, 5, 6], 10), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be at least 2")]
+    fn test_digits_base_1() {
+        let _ = digits(5u32, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be at least 2")]
+    fn test_digits_base_0() {
+        let _ = digits(5u32, 0);
+    }
+
+    #[test]
+    fn test_splitting_and_concatenating() {
+        assert_eq!(split_number(253000u64, 3), (253, 0));
+        assert_eq!(split_number(17u8, 5), (0, 17));
+        assert_eq!(concatenate(12u64, 345), Some(12345));
+        assert_eq!(concatenate(15u64, 0), Some(150));
+        assert_eq!(concatenate(25u8, 6), None);
+        assert_eq!(deconcatenate(12345u64, 45), Some(123));
+        assert_eq!(deconcatenate(12345u64, 12345), Some(0));
+        assert_eq!(deconcatenate(12345u64, 46), None);
+        assert_eq!(deconcatenate(150u64, 0), Some(15));
+    }
+
+    #[test]
+    fn test_reversing() {
+        assert_eq!(reverse_digits(1230u32), Some(321));
+        assert_eq!(reverse_digits(199u8), None);
+        assert!(is_palindrome(12321u32));
+        assert!(is_palindrome(7u8));
+        assert!(!is_palindrome(1230u32));
+        assert!(is_palindrome_in_base(0b10101u8, 2));
+    }
+}
@@ -0,0 +1,374 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Neg;
+
+use crate::error::AocError;
+use crate::point2::{Coordinate, Point2};
+
+/// One of the four directions on a grid or map. `Up` points to smaller `y`, like the lines of
+/// an input.
+///
+/// [GridDirection](crate::grid_direction::GridDirection) and
+/// [MapDirection](crate::map_direction::MapDirection) are the same type.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    #[default]
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions clockwise starting with [Direction::Up].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Position in [Direction::ALL].
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Turns clockwise by `quarter_turns` times 90°, counterclockwise for negative values.
+    pub fn rotate(&self, quarter_turns: i32) -> Self {
+        Self::ALL[(self.index() as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn right(&self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn left(&self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_right(&mut self) {
+        *self = self.right();
+    }
+
+    pub fn turn_left(&mut self) {
+        *self = self.left();
+    }
+
+    pub fn turn_around(&mut self) {
+        *self = self.opposite();
+    }
+
+    /// Offset of one step in this direction.
+    pub fn unit_vector<T>(&self) -> Point2<T>
+    where
+        T: Coordinate + Neg<Output = T>,
+    {
+        match self {
+            Direction::Up => Point2::new(T::ZERO, -T::ONE),
+            Direction::Right => Point2::new(T::ONE, T::ZERO),
+            Direction::Down => Point2::new(T::ZERO, T::ONE),
+            Direction::Left => Point2::new(-T::ONE, T::ZERO),
+        }
+    }
+
+    /// `^`, `>`, `v` or `<`.
+    pub fn to_char(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// `^`, `>`, `v` or `<`, the inverse of [Direction::to_char].
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// `U`, `R`, `D` or `L` in any case.
+    pub fn from_letter(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'U' => Some(Direction::Up),
+            'R' => Some(Direction::Right),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// `N`, `E`, `S` or `W` in any case, north is up.
+    pub fn from_compass(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'N' => Some(Direction::Up),
+            'E' => Some(Direction::Right),
+            'S' => Some(Direction::Down),
+            'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// `'0'` to `'3'` for the directions in [Direction::ALL]. Puzzles that number them
+    /// differently can use [Direction::rotate] on the result.
+    pub fn from_digit(c: char) -> Option<Self> {
+        c.to_digit(10)
+            .and_then(|digit| Self::ALL.get(digit as usize))
+            .copied()
+    }
+
+    pub fn to_digit(&self) -> char {
+        char::from(b'0' + self.index() as u8)
+    }
+}
+
+/// Accepts the arrows that [Display] writes. Inputs that use letters or digits pick the matching
+/// constructor, like [Direction::from_letter], so no other encoding slips through.
+impl TryFrom<char> for Direction {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Self::from_arrow(value).ok_or(AocError::UnexpectedChar {
+            found: value,
+            position: None,
+        })
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// One of the eight directions on a grid, diagonals included.
+///
+/// [Grid8WayDirection](crate::grid_direction::Grid8WayDirection) is the same type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction8 {
+    LeftUp,
+    Up,
+    RightUp,
+    Right,
+    RightDown,
+    Down,
+    LeftDown,
+    Left,
+}
+
+impl Direction8 {
+    /// All directions clockwise starting with [Direction8::LeftUp].
+    pub const ALL: [Direction8; 8] = [
+        Direction8::LeftUp,
+        Direction8::Up,
+        Direction8::RightUp,
+        Direction8::Right,
+        Direction8::RightDown,
+        Direction8::Down,
+        Direction8::LeftDown,
+        Direction8::Left,
+    ];
+
+    /// Position in [Direction8::ALL].
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Turns clockwise by `steps` times 45°, counterclockwise for negative values.
+    pub fn rotate(&self, steps: i32) -> Self {
+        Self::ALL[(self.index() as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+
+    /// Offset of one step in this direction.
+    pub fn unit_vector<T>(&self) -> Point2<T>
+    where
+        T: Coordinate + Neg<Output = T>,
+    {
+        let (x, y) = match self {
+            Direction8::LeftUp => (-T::ONE, -T::ONE),
+            Direction8::Up => (T::ZERO, -T::ONE),
+            Direction8::RightUp => (T::ONE, -T::ONE),
+            Direction8::Right => (T::ONE, T::ZERO),
+            Direction8::RightDown => (T::ONE, T::ONE),
+            Direction8::Down => (T::ZERO, T::ONE),
+            Direction8::LeftDown => (-T::ONE, T::ONE),
+            Direction8::Left => (-T::ONE, T::ZERO),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    /// Fails with the diagonal that has no four way counterpart.
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::Up => Ok(Direction::Up),
+            Direction8::Right => Ok(Direction::Right),
+            Direction8::Down => Ok(Direction::Down),
+            Direction8::Left => Ok(Direction::Left),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A set of [Direction]s in one byte, for example the directions a cell was already left in.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn bit(direction: Direction) -> u8 {
+        1 << direction.index()
+    }
+
+    pub fn contains(&self, direction: Direction) -> bool {
+        self.0 & Self::bit(direction) != 0
+    }
+
+    /// Adds `direction`, returns whether it was not in the set yet.
+    pub fn insert(&mut self, direction: Direction) -> bool {
+        let added = !self.contains(direction);
+        self.0 |= Self::bit(direction);
+        added
+    }
+
+    /// Removes `direction`, returns whether it was in the set.
+    pub fn remove(&mut self, direction: Direction) -> bool {
+        let removed = self.contains(direction);
+        self.0 &= !Self::bit(direction);
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Direction> + '_ {
+        Direction::ALL.into_iter().filter(|&d| self.contains(d))
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> Self {
+        let mut rtn = DirectionSet::new();
+        for direction in iter {
+            rtn.insert(direction);
+        }
+        rtn
+    }
+}
+
+#[cfg(test)]
+mod direction_test {
+    use crate::direction::{Direction, Direction8, DirectionSet};
+    use crate::point::MapPoint;
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Direction::Up.rotate(1), Direction::Right);
+        assert_eq!(Direction::Up.rotate(-1), Direction::Left);
+        assert_eq!(Direction::Left.rotate(6), Direction::Right);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+        assert_eq!(Direction8::LeftUp.rotate(-1), Direction8::Left);
+        assert_eq!(Direction8::RightUp.opposite(), Direction8::LeftDown);
+    }
+
+    #[test]
+    fn test_unit_vectors_match_point_rotation() {
+        for direction in Direction::ALL {
+            let vector = direction.unit_vector::<i64>();
+            assert_eq!(direction.right().unit_vector::<i64>(), vector.rotate_cw());
+            assert_eq!(Direction8::from(direction).unit_vector::<i64>(), vector);
+        }
+        assert_eq!(
+            Direction8::LeftDown.unit_vector::<i64>(),
+            MapPoint::new(-1, 1)
+        );
+    }
+
+    #[test]
+    fn test_parsing() {
+        for (arrow, letters, compass, expected) in [
+            ('^', "Uu", "Nn", Direction::Up),
+            ('>', "Rr", "Ee", Direction::Right),
+            ('v', "Dd", "Ss", Direction::Down),
+            ('<', "Ll", "Ww", Direction::Left),
+        ] {
+            assert_eq!(Direction::try_from(arrow).unwrap(), expected);
+            assert_eq!(Direction::try_from(expected.to_char()).unwrap(), expected);
+            for c in letters.chars() {
+                assert_eq!(Direction::from_letter(c), Some(expected));
+                assert!(Direction::try_from(c).is_err());
+            }
+            for c in compass.chars() {
+                assert_eq!(Direction::from_compass(c), Some(expected));
+                assert!(Direction::try_from(c).is_err());
+            }
+            assert_eq!(Direction::from_digit(expected.to_digit()), Some(expected));
+        }
+        assert!(Direction::try_from('x').is_err());
+        assert_eq!(Direction::from_arrow('V'), None);
+        assert_eq!(Direction::from_digit('4'), None);
+    }
+
+    #[test]
+    fn test_direction_set() {
+        let mut set = DirectionSet::new();
+        assert!(set.insert(Direction::Left));
+        assert!(!set.insert(Direction::Left));
+        assert!(set.insert(Direction::Up));
+        assert!(set.contains(Direction::Up) && !set.contains(Direction::Down));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Direction::Up, Direction::Left]
+        );
+        assert!(set.remove(Direction::Up));
+        assert_eq!(set.len(), 1);
+        assert_eq!(
+            Direction::ALL.into_iter().collect::<DirectionSet>().len(),
+            4
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Position of a character in the input. Lines and columns both start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Everything that can go wrong while reading and parsing an input.
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    Parse {
+        position: Position,
+        message: String,
+    },
+    /// A char that does not mean anything at this place. Conversions of a single char do not know
+    /// where it came from, the caller adds the position with [AocError::at].
+    UnexpectedChar {
+        found: char,
+        position: Option<Position>,
+    },
+    /// A puzzle parameter, see [Parameters](crate::solution::Parameters), with a value that does
+    /// not parse.
+    InvalidParameter {
+        name: String,
+        value: String,
+        message: String,
+    },
+    /// An input file that is in none of the places where it was looked for.
+    MissingInput {
+        what: String,
+        searched: Vec<PathBuf>,
+    },
+}
+
+impl AocError {
+    /// Sets the position of an [AocError::UnexpectedChar] that was raised without one.
+    pub fn at(self, position: Position) -> Self {
+        match self {
+            AocError::UnexpectedChar {
+                found,
+                position: None,
+            } => AocError::UnexpectedChar {
+                found,
+                position: Some(position),
+            },
+            e => e,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{e}"),
+            AocError::Parse { position, message } => write!(f, "{position}: {message}"),
+            AocError::UnexpectedChar {
+                found,
+                position: Some(position),
+            } => write!(f, "{position}: unexpected character '{found}'"),
+            AocError::UnexpectedChar {
+                found,
+                position: None,
+            } => write!(f, "unexpected character '{found}'"),
+            AocError::InvalidParameter {
+                name,
+                value,
+                message,
+            } => write!(f, "invalid value '{value}' for parameter {name}: {message}"),
+            AocError::MissingInput { what, searched } => {
+                let searched: Vec<String> =
+                    searched.iter().map(|p| p.display().to_string()).collect();
+                write!(f, "{what} not found, looked for {}", searched.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(value: io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+/// Error for an input that ends before `what` was found.
+pub fn unexpected_end(input: &str, what: &str) -> AocError {
+    AocError::Parse {
+        position: Position {
+            line: input.lines().count() + 1,
+            column: 1,
+        },
+        message: format!("missing {what}"),
+    }
+}
+
+/// A line of the input with its number, so errors can point at the token that broke parsing.
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Iterates over the lines of `input`, numbered from 1.
+pub fn input_lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input.lines().enumerate().map(|(i, text)| InputLine {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> InputLine<'a> {
+    pub fn position(&self, column: usize) -> Position {
+        Position {
+            line: self.number,
+            column,
+        }
+    }
+
+    /// Position of `token`, which has to be a slice of this line like the parts returned by
+    /// `split` or `trim`. Anything else points at the start of the line.
+    pub fn position_of(&self, token: &str) -> Position {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = match self.text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        };
+        self.position(column)
+    }
+
+    /// Position right after the last character, where a missing token would have been.
+    pub fn end(&self) -> Position {
+        self.position(self.text.chars().count() + 1)
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> AocError {
+        AocError::Parse {
+            position: self.position_of(token),
+            message: message.into(),
+        }
+    }
+
+    pub fn parse<T>(&self, token: &str) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token
+            .parse()
+            .map_err(|e| self.error(token, format!("could not parse \"{token}\": {e}")))
+    }
+
+    /// Takes the next token, failing at the end of the line if there is none.
+    pub fn next<'b>(
+        &self,
+        tokens: &mut impl Iterator<Item = &'b str>,
+        what: &str,
+    ) -> Result<&'b str, AocError> {
+        tokens.next().ok_or_else(|| AocError::Parse {
+            position: self.end(),
+            message: format!("missing {what}"),
+        })
+    }
+
+    /// Parses the next token, see [InputLine::next] and [InputLine::parse].
+    pub fn parse_next<'b, T>(
+        &self,
+        tokens: &mut impl Iterator<Item = &'b str>,
+        what: &str,
+    ) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = self.next(tokens, what)?;
+        self.parse(token)
+    }
+}
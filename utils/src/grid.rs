@@ -1,10 +1,48 @@
 use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
 
+use crate::error::{input_lines, AocError, Position};
+use crate::grid_direction::{Grid8WayDirection, GridDirection};
 use crate::grid_point::GridPoint;
+use crate::search::{bfs, dijkstra, SearchResult};
 
+/// A rectangular grid, stored row by row in a single `Vec`.
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Grid<T> {
-    pub data: Vec<Vec<T>>,
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// Which cells are next to each other in the pathfinding methods of [Grid].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Up, right, down and left, the four [GridDirection]s.
+    FourWay,
+    /// The diagonals as well, all [Grid8WayDirection]s.
+    EightWay,
+}
+
+/// A search state that stands on a cell of a grid, like a position or a position with a facing.
+pub trait GridState: Eq + Hash + Clone {
+    fn position(&self) -> GridPoint;
+}
+
+impl GridState for GridPoint {
+    fn position(&self) -> GridPoint {
+        *self
+    }
+}
+
+impl<S> GridState for (GridPoint, S)
+where
+    S: Eq + Hash + Clone,
+{
+    fn position(&self) -> GridPoint {
+        self.0
+    }
 }
 
 impl<T> Display for Grid<T>
@@ -16,15 +54,36 @@ where
     }
 }
 
-impl From<Vec<Vec<char>>> for Grid<char> {
-    fn from(value: Vec<Vec<char>>) -> Self {
-        Self { data: value }
+/// Panics if the rows have different lengths, use [Grid::from_rows] for untrusted data.
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(value: Vec<Vec<T>>) -> Self {
+        match Self::from_rows(value) {
+            Ok(grid) => grid,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T> Index<GridPoint> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: GridPoint) -> &Self::Output {
+        &self.data[self.offset(point.x, point.y)]
     }
 }
 
-impl From<Vec<Vec<u8>>> for Grid<u8> {
-    fn from(value: Vec<Vec<u8>>) -> Self {
-        Self { data: value }
+impl<T> IndexMut<GridPoint> for Grid<T> {
+    fn index_mut(&mut self, point: GridPoint) -> &mut Self::Output {
+        let offset = self.offset(point.x, point.y);
+        &mut self.data[offset]
     }
 }
 
@@ -33,18 +92,14 @@ where
     T: Eq,
 {
     pub fn find_first(&self, to_find: &T) -> Option<GridPoint> {
-        for (y, row) in self.data.iter().enumerate() {
-            for (x, column) in row.iter().enumerate() {
-                if column == to_find {
-                    return Some(GridPoint { x, y });
-                }
-            }
-        }
-        None
+        self.data
+            .iter()
+            .position(|i| i == to_find)
+            .map(|offset| self.point_of(offset))
     }
 
     pub fn count_for(&self, value: &T) -> usize {
-        self.data.iter().flatten().filter(|i| *i == value).count()
+        self.data.iter().filter(|i| *i == value).count()
     }
 }
 
@@ -54,24 +109,112 @@ impl<T> Grid<T> {
         T: Clone,
     {
         Self {
-            data: vec![vec![fill; width]; height],
+            data: vec![fill; width * height],
+            width,
+            height,
         }
     }
 
+    /// Builds a grid out of rows, which all need the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut data = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ragged_row(y + 1, row.len(), width));
+            }
+            data.extend(row);
+        }
+        Ok(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per line, converting every character with `convert`. Lines are trimmed and
+    /// have to be of the same length.
+    pub fn parse_with(input: &str, mut convert: impl FnMut(char) -> T) -> Result<Self, AocError> {
+        let mut data = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input_lines(input) {
+            let before = data.len();
+            data.extend(line.text.trim().chars().map(&mut convert));
+            let length = data.len() - before;
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(ragged_row(line.number, length, width));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self {
+            data,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     pub fn get_max_x(&self) -> usize {
-        self.data.iter().map(|i| i.len()).max().unwrap_or(0)
+        self.width
     }
 
     pub fn get_max_y(&self) -> usize {
-        self.data.len()
+        self.height
+    }
+
+    fn offset(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+
+    fn point_of(&self, offset: usize) -> GridPoint {
+        GridPoint {
+            x: offset % self.width,
+            y: offset / self.width,
+        }
+    }
+
+    pub fn contains(&self, point: &GridPoint) -> bool {
+        point.x < self.width && point.y < self.height
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        self.data.get(y).and_then(|i| i.get(x))
+        if x < self.width && y < self.height {
+            self.data.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.data.get_mut(y * self.width + x)
+        } else {
+            None
+        }
     }
 
     pub fn set(&mut self, x: usize, y: usize, value: T) {
-        self.data[y][x] = value;
+        let offset = self.offset(x, y);
+        self.data[offset] = value;
     }
 
     pub fn get_from_point(&self, point: &GridPoint) -> Option<&T> {
@@ -82,53 +225,334 @@ impl<T> Grid<T> {
         self.set(point.x, point.y, value)
     }
 
-    pub fn create_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Self
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter_points(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(offset, value)| (self.point_of(offset), value))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        let start = self.offset(0, y);
+        &self.data[start..start + self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        let start = self.offset(0, y);
+        let width = self.width;
+        &mut self.data[start..start + width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on 0, an empty grid has no rows anyway.
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Whether a `width` x `height` window at `x`, `y` lies completely inside the grid.
+    fn contains_window(&self, x: usize, y: usize, width: usize, height: usize) -> bool {
+        x.checked_add(width).is_some_and(|end| end <= self.width)
+            && y.checked_add(height).is_some_and(|end| end <= self.height)
+    }
+
+    /// Copy of the `width` x `height` window at `x`, `y`, `None` if it does not fit into the grid.
+    pub fn create_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self>
     where
-        T: Copy,
+        T: Clone,
     {
-        let mut new_data: Vec<Vec<T>> = Vec::with_capacity(height);
-        for y in y..y + width {
-            let mut row = Vec::with_capacity(width);
-            for x in x..x + width {
-                let point = self.get(x, y).unwrap();
-                row.push(*point);
-            }
-            new_data.push(row);
+        self.get_sub_grid(x, y, width, height)
+            .map(|view| view.to_grid())
+    }
+
+    /// View of the `width` x `height` window at `x`, `y`, `None` if it does not fit into the grid.
+    pub fn get_sub_grid(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<GridView<'_, T>> {
+        self.contains_window(x, y, width, height)
+            .then_some(GridView {
+                grid: self,
+                x,
+                y,
+                width,
+                height,
+            })
+    }
+
+    /// Writes `sub_grid` into this grid with its top left corner at `x`, `y`.
+    pub fn set_sub_grid(&mut self, x: usize, y: usize, sub_grid: &Grid<T>)
+    where
+        T: Clone,
+    {
+        assert!(
+            self.contains_window(x, y, sub_grid.width, sub_grid.height),
+            "a {}x{} grid does not fit at ({x}, {y})",
+            sub_grid.width,
+            sub_grid.height
+        );
+        for (dy, row) in sub_grid.rows().enumerate() {
+            self.row_mut(y + dy)[x..x + sub_grid.width].clone_from_slice(row);
+        }
+    }
+
+    /// All `width` x `height` windows, moving one cell at a time, row by row.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let xs = (self.width + 1).saturating_sub(width);
+        let ys = (self.height + 1).saturating_sub(height);
+        (0..ys).flat_map(move |y| {
+            (0..xs).map(move |x| GridView {
+                grid: self,
+                x,
+                y,
+                width,
+                height,
+            })
+        })
+    }
+
+    /// Splits the grid into `width` x `height` tiles, row by row. Cells at the right and bottom
+    /// border that do not fill a whole tile are left out.
+    pub fn tiles(&self, width: usize, height: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        assert!(width > 0 && height > 0, "tiles need a size");
+        let xs = self.width / width;
+        let ys = self.height / height;
+        (0..ys).flat_map(move |y| {
+            (0..xs).map(move |x| GridView {
+                grid: self,
+                x: x * width,
+                y: y * height,
+                width,
+                height,
+            })
+        })
+    }
+
+    /// Grid mirrored along the diagonal from the top left, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.map_positions(self.height, self.width, |x, y| GridPoint::new(y, x))
+    }
+
+    /// Grid rotated by 90° clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.map_positions(height, self.width, |x, y| GridPoint::new(y, height - 1 - x))
+    }
+
+    /// Grid rotated by 90° counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.map_positions(self.height, width, |x, y| GridPoint::new(width - 1 - y, x))
+    }
+
+    /// Grid mirrored left to right, every row is reversed.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.map_positions(width, self.height, |x, y| GridPoint::new(width - 1 - x, y))
+    }
+
+    /// Grid mirrored top to bottom, the order of the rows is reversed.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.map_positions(self.width, height, |x, y| GridPoint::new(x, height - 1 - y))
+    }
+
+    /// Grid repeated `nx` times to the right and `ny` times downwards.
+    pub fn tile(&self, nx: usize, ny: usize) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.map_positions(width * nx, height * ny, |x, y| {
+            GridPoint::new(x % width, y % height)
+        })
+    }
+
+    /// Replaces every cell by `N` cells next to each other, so the grid gets `N` times as wide.
+    pub fn expand_with<const N: usize>(&self, mut expand: impl FnMut(T) -> [T; N]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: self.data.iter().cloned().flat_map(&mut expand).collect(),
+            width: self.width * N,
+            height: self.height,
         }
-        Grid { data: new_data }
     }
 
-    pub fn get_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<&T> {
-        let mut new_data: Vec<Vec<&T>> = Vec::with_capacity(height);
-        for y in y..y + width {
-            let mut row = Vec::with_capacity(width);
-            for x in x..x + width {
-                let point = self.get(x, y).unwrap();
-                row.push(point);
+    /// New `width` x `height` grid, taking every cell from the position `source` returns for it.
+    fn map_positions(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> GridPoint,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(self[source(x, y)].clone());
             }
-            new_data.push(row);
         }
-        Grid { data: new_data }
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// The cells next to `point` that are inside the grid.
+    pub fn neighbors(&self, point: &GridPoint, adjacency: Adjacency) -> Vec<GridPoint> {
+        // The checked steps take the last index, not the size.
+        let (max_x, max_y) = (self.width.saturating_sub(1), self.height.saturating_sub(1));
+        match adjacency {
+            Adjacency::FourWay => GridDirection::ALL
+                .iter()
+                .filter_map(|d| point.next_by_direction_with_check(d, max_x, max_y))
+                .collect(),
+            Adjacency::EightWay => Grid8WayDirection::ALL
+                .iter()
+                .filter_map(|d| point.next_by_8direction_with_check(d, max_x, max_y))
+                .collect(),
+        }
+    }
+
+    /// Breadth first search over the whole grid. `passable` gets the cell a step starts on and
+    /// the cell it ends on and decides whether the step is allowed.
+    pub fn bfs_from(
+        &self,
+        start: GridPoint,
+        adjacency: Adjacency,
+        passable: impl Fn(&T, &T) -> bool,
+    ) -> SearchResult<GridPoint> {
+        bfs(
+            start,
+            |position| {
+                let from = &self[*position];
+                self.neighbors(position, adjacency)
+                    .into_iter()
+                    .filter(|neighbor| passable(from, &self[*neighbor]))
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        )
+    }
+
+    /// Number of steps from `start` to every cell, `None` where it cannot be reached. See
+    /// [Grid::bfs_from] for `passable`.
+    pub fn bfs_distances_from(
+        &self,
+        start: GridPoint,
+        adjacency: Adjacency,
+        passable: impl Fn(&T, &T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut rtn = Grid::with_width_height(self.width, self.height, None);
+        for (position, distance) in self.bfs_from(start, adjacency, passable).distances() {
+            rtn[*position] = Some(distance);
+        }
+        rtn
+    }
+
+    /// One of the shortest paths from `start` to `end`, both included. See [Grid::bfs_from] for
+    /// `passable`.
+    pub fn shortest_path(
+        &self,
+        start: GridPoint,
+        end: GridPoint,
+        adjacency: Adjacency,
+        passable: impl Fn(&T, &T) -> bool,
+    ) -> Option<Vec<GridPoint>> {
+        bfs(
+            start,
+            |position| {
+                let from = &self[*position];
+                self.neighbors(position, adjacency)
+                    .into_iter()
+                    .filter(|neighbor| passable(from, &self[*neighbor]))
+                    .collect::<Vec<_>>()
+            },
+            |position| *position == end,
+        )
+        .path()
+    }
+
+    /// Dijkstra's algorithm over states that carry more than a position, like the facing of a
+    /// reindeer. `next_states` lists the states reachable from a state, the ones outside the grid
+    /// are dropped. `cost` gets both states and the cell the step ends on and returns `None` if the
+    /// step is not allowed.
+    pub fn dijkstra_with_state<S, I>(
+        &self,
+        start: S,
+        mut next_states: impl FnMut(&S) -> I,
+        mut cost: impl FnMut(&S, &S, &T) -> Option<usize>,
+        is_goal: impl FnMut(&S) -> bool,
+    ) -> SearchResult<S>
+    where
+        S: GridState,
+        I: IntoIterator<Item = S>,
+    {
+        dijkstra(
+            start,
+            |state| {
+                next_states(state)
+                    .into_iter()
+                    .filter(|next| self.contains(&next.position()))
+                    .filter_map(|next| {
+                        let cell = &self[next.position()];
+                        cost(state, &next, cell).map(|cost| (next, cost))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            is_goal,
+        )
     }
 
     pub fn print_data(&self)
     where
         T: Display,
     {
-        for row in self.data.iter() {
-            for i in row.iter() {
-                print!("{}", *i);
-            }
-            println!();
-        }
+        print!("{}", self.grid_to_string());
     }
 
     pub fn grid_to_string(&self) -> String
     where
         T: Display,
     {
-        let mut rtn = String::with_capacity(self.get_max_x() * self.get_max_y() + self.get_max_y());
-        for row in self.data.iter() {
+        let mut rtn = String::with_capacity(self.width * self.height + self.height);
+        for row in self.rows() {
             for i in row.iter() {
                 rtn.push_str(&format!("{}", *i));
             }
@@ -137,3 +561,192 @@ impl<T> Grid<T> {
         rtn
     }
 }
+
+/// A rectangular window into a [Grid], indexed relative to its top left corner.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Index<GridPoint> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, point: GridPoint) -> &Self::Output {
+        assert!(
+            point.x < self.width && point.y < self.height,
+            "({}, {}) is outside of the {}x{} view",
+            point.x,
+            point.y,
+            self.width,
+            self.height
+        );
+        &self.grid[GridPoint::new(self.x + point.x, self.y + point.y)]
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Position of the top left corner in the grid.
+    pub fn origin(&self) -> GridPoint {
+        GridPoint::new(self.x, self.y)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (grid, x, width) = (self.grid, self.x, self.width);
+        (self.y..self.y + self.height).map(move |y| &grid.row(y)[x..x + width])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &'a T> {
+        assert!(x < self.width, "column {x} is outside of the view");
+        self.rows().map(move |row| &row[x])
+    }
+
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            data: self.iter().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+fn ragged_row(line: usize, length: usize, width: usize) -> AocError {
+    AocError::Parse {
+        position: Position { line, column: 1 },
+        message: format!("row has {length} cells, expected {width} like the first row"),
+    }
+}
+
+#[cfg(test)]
+mod grid_test {
+    use crate::grid::{Adjacency, Grid};
+    use crate::grid_point::GridPoint;
+
+    fn grid(input: &str) -> Grid<char> {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = grid("abc\ndef\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[GridPoint::new(2, 1)], 'f');
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.get(3, 0), None);
+        assert!("abc\nde\n".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn test_sub_grids() {
+        let mut grid = grid("abcd\nefgh\nijkl\n");
+        let view = grid.get_sub_grid(1, 1, 3, 2).unwrap();
+        assert_eq!(view.to_grid().to_string(), "fgh\njkl\n");
+        assert!(grid.get_sub_grid(2, 1, 3, 2).is_none());
+        assert_eq!(grid.windows(3, 2).count(), 4);
+        assert_eq!(
+            grid.tiles(2, 1)
+                .map(|t| t.iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ab", "cd", "ef", "gh", "ij", "kl"]
+        );
+
+        let patch = grid.create_sub_grid(0, 0, 2, 2).unwrap();
+        grid.set_sub_grid(2, 1, &patch);
+        assert_eq!(grid.to_string(), "abcd\nefab\nijef\n");
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = grid("abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(
+            grid.rotate_cw().rotate_cw(),
+            grid.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_tile_and_expand() {
+        let grid = grid("ab\n");
+        assert_eq!(grid.tile(2, 2).to_string(), "abab\nabab\n");
+        assert_eq!(
+            grid.expand_with(|c| [c, c.to_ascii_uppercase()])
+                .to_string(),
+            "aAbB\n"
+        );
+    }
+
+    #[test]
+    fn test_pathfinding() {
+        let grid = grid("S.#\n#.#\n..E\n");
+        let open = |_: &char, to: &char| *to != '#';
+        let (start, end) = (GridPoint::new(0, 0), GridPoint::new(2, 2));
+
+        let path = grid
+            .shortest_path(start, end, Adjacency::FourWay, open)
+            .unwrap();
+        assert_eq!(path.len(), 5);
+        let diagonal = grid
+            .shortest_path(start, end, Adjacency::EightWay, open)
+            .unwrap();
+        assert_eq!(diagonal.len(), 3);
+
+        let distances = grid.bfs_distances_from(start, Adjacency::FourWay, open);
+        assert_eq!(distances[GridPoint::new(0, 2)], Some(4));
+        assert_eq!(distances[GridPoint::new(2, 0)], None);
+
+        // Two steps to the right for 5 each and two steps down for 1 each.
+        let result = grid.dijkstra_with_state(
+            start,
+            |p| grid.neighbors(p, Adjacency::FourWay),
+            |from, to, &cell| match cell {
+                '#' => None,
+                _ if to.x > from.x => Some(5),
+                _ => Some(1),
+            },
+            |p| *p == end,
+        );
+        assert_eq!(result.cost(), Some(12));
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::VecDeque;
+use std::ops::Index;
+
+use crate::vector3::{bounding_box, Vector3};
+
+/// A dense box of voxels over signed coordinates, the 3D counterpart of [Map](crate::map::Map)
+/// but with a fixed size. Reading outside the box gives `None`.
+///
+/// Cells are stored x first, then y, then z.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Grid3<T> {
+    data: Vec<T>,
+    min: Vector3,
+    width: usize,
+    height: usize,
+    depth: usize,
+}
+
+impl<T: Clone> Grid3<T> {
+    /// A box from `min` to `max`, both included, with every cell set to `fill`. Empty if `max`
+    /// lies below `min` on any axis.
+    pub fn new(min: Vector3, max: Vector3, fill: T) -> Self {
+        let size = |from: i64, to: i64| (to - from + 1).max(0) as usize;
+        let (width, height, depth) = (size(min.x, max.x), size(min.y, max.y), size(min.z, max.z));
+        Self {
+            data: vec![fill; width * height * depth],
+            min,
+            width,
+            height,
+            depth,
+        }
+    }
+
+    /// The smallest box around all `points` grown by `padding` on every side, the points get
+    /// their value and everything else is `fill`. A padding of 1 leaves a free layer around the
+    /// shape, so a [Grid3::flood_fill] from [Grid3::min] reaches it from every side.
+    pub fn from_points(
+        points: impl IntoIterator<Item = (Vector3, T)>,
+        fill: T,
+        padding: i64,
+    ) -> Self {
+        let points: Vec<(Vector3, T)> = points.into_iter().collect();
+        let Some((min, max)) = bounding_box(points.iter().map(|(point, _)| *point)) else {
+            return Self::new(Vector3::ZERO, -Vector3::new(1, 1, 1), fill);
+        };
+        let padding = Vector3::new(padding, padding, padding);
+        let mut rtn = Self::new(min - padding, max + padding, fill);
+        for (point, value) in points {
+            rtn.set(&point, value);
+        }
+        rtn
+    }
+}
+
+impl<T> Grid3<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Corner with the smallest coordinates.
+    pub fn min(&self) -> Vector3 {
+        self.min
+    }
+
+    /// Corner with the largest coordinates, part of the box.
+    pub fn max(&self) -> Vector3 {
+        self.min + Vector3::new(self.width as i64, self.height as i64, self.depth as i64)
+            - Vector3::new(1, 1, 1)
+    }
+
+    fn index_of(&self, point: &Vector3) -> Option<usize> {
+        let offset = *point - self.min;
+        let x = usize::try_from(offset.x).ok()?;
+        let y = usize::try_from(offset.y).ok()?;
+        let z = usize::try_from(offset.z).ok()?;
+        (x < self.width && y < self.height && z < self.depth)
+            .then_some((z * self.height + y) * self.width + x)
+    }
+
+    fn point_of(&self, index: usize) -> Vector3 {
+        let (x, rest) = (index % self.width, index / self.width);
+        let (y, z) = (rest % self.height, rest / self.height);
+        self.min + Vector3::new(x as i64, y as i64, z as i64)
+    }
+
+    pub fn contains(&self, point: &Vector3) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: &Vector3) -> Option<&T> {
+        self.index_of(point).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, point: &Vector3) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.data[index])
+    }
+
+    /// Sets the cell at `point`, panics if it lies outside the box.
+    pub fn set(&mut self, point: &Vector3, value: T) {
+        let index = self
+            .index_of(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"));
+        self.data[index] = value;
+    }
+
+    /// All cells with their position, x first, then y, then z.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector3, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.point_of(index), value))
+    }
+
+    /// The face neighbours of `point` that lie inside the box.
+    pub fn neighbors(&self, point: &Vector3) -> impl Iterator<Item = Vector3> + '_ {
+        point.neighbors().filter(|neighbor| self.contains(neighbor))
+    }
+
+    /// Every cell that can be reached from `start` through face neighbours whose value
+    /// `can_enter` holds for. `start` itself counts if `can_enter` holds for it.
+    pub fn flood_fill(&self, start: &Vector3, can_enter: impl Fn(&T) -> bool) -> Grid3<bool> {
+        let mut reached = Grid3::new(self.min, self.max(), false);
+        if !self.get(start).is_some_and(&can_enter) {
+            return reached;
+        }
+
+        reached.set(start, true);
+        let mut queue = VecDeque::from([*start]);
+        while let Some(point) = queue.pop_front() {
+            for neighbor in self.neighbors(&point) {
+                if !reached[&neighbor] && can_enter(&self[&neighbor]) {
+                    reached.set(&neighbor, true);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        reached
+    }
+}
+
+impl<T> Index<&Vector3> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, point: &Vector3) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod grid3_test {
+    use crate::grid3::Grid3;
+    use crate::vector3::Vector3;
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = Grid3::new(Vector3::new(-1, 0, 2), Vector3::new(1, 3, 2), 0);
+        assert_eq!((grid.width(), grid.height(), grid.depth()), (3, 4, 1));
+        assert_eq!(grid.max(), Vector3::new(1, 3, 2));
+
+        grid.set(&Vector3::new(-1, 3, 2), 5);
+        assert_eq!(grid.get(&Vector3::new(-1, 3, 2)), Some(&5));
+        assert_eq!(grid.get(&Vector3::new(-1, 3, 3)), None);
+        assert_eq!(grid.get(&Vector3::new(-2, 0, 2)), None);
+        assert_eq!(
+            grid.iter().find(|(_, &v)| v == 5).map(|(p, _)| p),
+            Some(Vector3::new(-1, 3, 2))
+        );
+        assert_eq!(grid.neighbors(&Vector3::new(-1, 0, 2)).count(), 2);
+        assert!(Grid3::new(Vector3::ZERO, Vector3::new(1, -1, 1), 0).is_empty());
+    }
+
+    #[test]
+    fn test_flood_fill_finds_the_outside() {
+        // A hollow 3x3x3 cube, the air in its middle is not connected to the outside.
+        let shell = (0..3)
+            .flat_map(|z| (0..3).flat_map(move |y| (0..3).map(move |x| Vector3::new(x, y, z))))
+            .filter(|point| *point != Vector3::new(1, 1, 1))
+            .map(|point| (point, true));
+        let grid = Grid3::from_points(shell, false, 1);
+        assert_eq!(grid.len(), 125);
+
+        let outside = grid.flood_fill(&grid.min(), |&solid| !solid);
+        assert_eq!(
+            outside.iter().filter(|(_, &reached)| reached).count(),
+            125 - 27
+        );
+        assert!(!outside[&Vector3::new(1, 1, 1)]);
+
+        let inside = grid.flood_fill(&Vector3::new(1, 1, 1), |&solid| !solid);
+        assert_eq!(inside.iter().filter(|(_, &reached)| reached).count(), 1);
+        assert_eq!(grid.flood_fill(&Vector3::ZERO, |&solid| !solid).len(), 125);
+        assert!(grid
+            .flood_fill(&Vector3::ZERO, |&solid| !solid)
+            .iter()
+            .all(|(_, &reached)| !reached));
+    }
+}
@@ -1,18 +1,10 @@
-use std::ops::Add;
-
 use crate::grid_direction::{Grid8WayDirection, GridDirection};
+use crate::point2::Point2;
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct GridPoint {
-    pub x: usize,
-    pub y: usize,
-}
+/// A position in a [Grid](crate::grid::Grid), `(0, 0)` is the top left cell.
+pub type GridPoint = Point2<usize>;
 
 impl GridPoint {
-    pub fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-
     pub fn generate_neighbors_with_check(&self, max_x: usize, max_y: usize) -> Vec<GridPoint> {
         let mut rtn = Vec::with_capacity(8);
         if self.x > 0 && self.y > 0 {
@@ -193,10 +185,6 @@ impl GridPoint {
         }
     }
 
-    pub fn manhatten_distance(&self, other: &Self) -> usize {
-        self.x.abs_diff(other.x).add(self.y.abs_diff(other.y))
-    }
-
     pub fn move_direction(&mut self, direction: &GridDirection) {
         match direction {
             GridDirection::Up => self.move_up(),
@@ -0,0 +1,159 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+
+/// Environment variable pointing at a folder with the inputs of everyone's own account, laid out
+/// as `<year>/<day>.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Folder in the workspace root with the same layout as [INPUT_DIR_VAR].
+pub const SHARED_INPUT_DIR: &str = "inputs";
+
+/// Year of day crates that are not inside a year folder.
+pub const DEFAULT_YEAR: u16 = 2024;
+
+/// Name of the first example of a day. Further examples are usually called `example_2`,
+/// `example_e` and so on, but any name works.
+pub const EXAMPLE: &str = "example";
+
+/// Finds the input and the examples of a single day.
+///
+/// A file is looked up in this order, the first one that exists wins:
+///
+/// 1. `$AOC_INPUT_DIR/<year>/<day>.txt`, `<day>_<example>.txt` for examples
+/// 2. `<workspace>/inputs/<year>/<day>.txt`, same names
+/// 3. `<day crate>/input/input.txt`, `input_test.txt` for `example`, `input_test_2.txt` for
+///    `example_2` and `<name>.txt` for any other example
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    crate_dir: PathBuf,
+    workspace_dir: PathBuf,
+    input_dir: Option<PathBuf>,
+    year: u16,
+    day: u8,
+}
+
+impl InputResolver {
+    /// Resolver for the day crate in `crate_dir`, usually `env!("CARGO_MANIFEST_DIR")`.
+    ///
+    /// The day comes from the `dXX` folder name. If the crate lives in a year folder like
+    /// `2025/d01` the year comes from there, otherwise it is [DEFAULT_YEAR].
+    pub fn for_crate(crate_dir: impl AsRef<Path>) -> Self {
+        let crate_dir = crate_dir.as_ref();
+        let day = folder_name(crate_dir)
+            .and_then(|name| name.strip_prefix('d'))
+            .and_then(|day| day.parse().ok())
+            .unwrap_or_else(|| panic!("{} is not a day crate", crate_dir.display()));
+
+        let parent = crate_dir.parent().unwrap_or(crate_dir);
+        let (year, workspace_dir) = match folder_name(parent).and_then(|name| name.parse().ok()) {
+            Some(year) => (year, parent.parent().unwrap_or(parent)),
+            None => (DEFAULT_YEAR, parent),
+        };
+
+        InputResolver {
+            crate_dir: crate_dir.to_path_buf(),
+            workspace_dir: workspace_dir.to_path_buf(),
+            input_dir: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            year,
+            day,
+        }
+    }
+
+    /// Uses `input_dir` instead of the folder from [INPUT_DIR_VAR].
+    pub fn with_input_dir(mut self, input_dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = Some(input_dir.into());
+        self
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Folder that downloaded inputs go to, the first one that is searched.
+    pub fn download_dir(&self) -> PathBuf {
+        match &self.input_dir {
+            Some(input_dir) => input_dir.clone(),
+            None => self.workspace_dir.join(SHARED_INPUT_DIR),
+        }
+    }
+
+    /// Path of the puzzle input.
+    pub fn input(&self) -> Result<PathBuf, AocError> {
+        self.find(None)
+    }
+
+    /// Path of the example called `name`.
+    pub fn example(&self, name: &str) -> Result<PathBuf, AocError> {
+        self.find(Some(name))
+    }
+
+    /// All paths that are tried for the input (`None`) or an example, in order.
+    pub fn candidates(&self, example: Option<&str>) -> Vec<PathBuf> {
+        let file_name = match example {
+            None => format!("{:0>2}.txt", self.day),
+            Some(name) => format!("{:0>2}_{name}.txt", self.day),
+        };
+        let year = self.year.to_string();
+
+        let mut rtn = Vec::with_capacity(3);
+        if let Some(input_dir) = &self.input_dir {
+            rtn.push(input_dir.join(&year).join(&file_name));
+        }
+        rtn.push(
+            self.workspace_dir
+                .join(SHARED_INPUT_DIR)
+                .join(&year)
+                .join(&file_name),
+        );
+        rtn.push(self.crate_dir.join("input").join(crate_file_name(example)));
+        rtn
+    }
+
+    fn find(&self, example: Option<&str>) -> Result<PathBuf, AocError> {
+        let candidates = self.candidates(example);
+        if let Some(found) = candidates.iter().find(|path| path.is_file()) {
+            return Ok(found.clone());
+        }
+
+        let what = match example {
+            None => String::from("input"),
+            Some(name) => format!("example '{name}'"),
+        };
+        Err(AocError::MissingInput {
+            what: format!("{what} of day {:0>2} ({})", self.day, self.year),
+            searched: candidates,
+        })
+    }
+}
+
+fn folder_name(path: &Path) -> Option<&str> {
+    path.file_name()?.to_str()
+}
+
+/// Name of the file in the `input` folder of a day crate, which predates the named examples.
+fn crate_file_name(example: Option<&str>) -> String {
+    match example {
+        None => String::from("input.txt"),
+        Some(EXAMPLE) => String::from("input_test.txt"),
+        Some(name) => match name.strip_prefix("example_") {
+            Some(suffix) => format!("input_test_{suffix}.txt"),
+            None => format!("{name}.txt"),
+        },
+    }
+}
+
+/// Path of the puzzle input of the day crate in `crate_dir`, see [InputResolver].
+pub fn input_path(crate_dir: &str) -> Result<PathBuf, AocError> {
+    InputResolver::for_crate(crate_dir).input()
+}
+
+/// Path of the example called `name` of the day crate in `crate_dir`, see [InputResolver].
+pub fn example_path(crate_dir: &str, name: &str) -> Result<PathBuf, AocError> {
+    InputResolver::for_crate(crate_dir).example(name)
+}
@@ -1,120 +1,104 @@
 use std::{
-    fs::File,
+    fmt::Display,
+    fs::{self, File},
     io::{BufRead, BufReader},
-    path::{Path, PathBuf},
+    path::Path,
+    str::FromStr,
 };
 
+use crate::error::{input_lines, AocError};
+
+pub mod answers;
+pub mod digits;
+pub mod direction;
+pub mod error;
 pub mod grid;
+pub mod grid3;
 pub mod grid_direction;
 pub mod grid_point;
-pub mod hash_point_map;
+pub mod inputs;
 pub mod map;
 pub mod map_direction;
+pub mod math;
+pub mod parse;
 pub mod point;
-pub mod utils;
+pub mod point2;
+pub mod regions;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
 pub mod vector3;
 
-pub fn parse_file_into<T>(input_file: &Path) -> Vec<T>
+pub fn parse_file_into<T>(input_file: &Path) -> Result<Vec<T>, AocError>
 where
     T: From<String>,
 {
-    let file = File::open(input_file).unwrap();
+    let file = File::open(input_file)?;
     let reader = BufReader::new(file);
 
     reader
         .lines()
-        .map(|l| l.unwrap().into())
-        .collect::<Vec<T>>()
-}
-
-pub fn parse_into_usize_vector(input_file: &Path) -> Vec<usize> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .parse::<usize>()
-                .expect("Could not parse \"{l}\" into usize")
-        })
-        .collect::<Vec<usize>>()
-}
-
-pub fn parse_into_i64_vector(input_file: &str) -> Vec<i64> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .parse::<i64>()
-                .expect("Could not parse \"{l}\" into i64")
-        })
-        .collect::<Vec<i64>>()
-}
-
-pub fn get_input_path(cargo_manifest_dir_path: &str) -> PathBuf {
-    Path::new(cargo_manifest_dir_path)
-        .join("input")
-        .join("input.txt")
+        .map(|l| Ok(l?.into()))
+        .collect::<Result<Vec<T>, AocError>>()
 }
 
-pub fn get_test_input_path(src_path: &str) -> PathBuf {
-    Path::new(src_path).join("input").join("input_test.txt")
+pub fn parse_into_usize_vector(input_file: &Path) -> Result<Vec<usize>, AocError> {
+    parse_str_into_vector(&fs::read_to_string(input_file)?)
 }
 
-pub fn get_test_input_2_path(src_path: &str) -> PathBuf {
-    Path::new(src_path).join("input").join("input_test_2.txt")
+pub fn parse_into_i64_vector(input_file: &str) -> Result<Vec<i64>, AocError> {
+    parse_str_into_vector(&fs::read_to_string(input_file)?)
 }
 
-pub fn get_test_input_e_path(src_path: &str) -> PathBuf {
-    Path::new(src_path).join("input").join("input_test_e.txt")
-}
-
-fn gcd(a: usize, b: usize) -> usize {
-    match ((a, b), (a & 1, b & 1)) {
-        ((x, y), _) if x == y => y,
-        ((0, x), _) | ((x, 0), _) => x,
-        ((x, y), (0, 1)) | ((y, x), (1, 0)) => gcd(x >> 1, y),
-        ((x, y), (0, 0)) => gcd(x >> 1, y >> 1) << 1,
-        ((x, y), (1, 1)) => {
-            let (x, y) = (x.min(y), x.max(y));
-            gcd((y - x) >> 1, x)
-        }
-        _ => unreachable!(),
-    }
+/// Parses every line of `input` into one value, reporting the line that could not be parsed.
+pub fn parse_str_into_vector<T>(input: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input_lines(input)
+        .map(|line| line.parse(line.text.trim()))
+        .collect()
 }
 
-pub fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
+pub fn parse_str_into<T>(input: &str) -> Vec<T>
+where
+    T: From<String>,
+{
+    input.lines().map(|l| l.to_string().into()).collect()
 }
 
-pub fn parse_into_char_vector_vector(input_file: &Path) -> Vec<Vec<char>> {
-    let file = File::open(input_file).unwrap();
+pub fn parse_into_char_vector_vector(input_file: &Path) -> Result<Vec<Vec<char>>, AocError> {
+    let file = File::open(input_file)?;
     let reader = BufReader::new(file);
 
     let mut rtn = Vec::new();
 
     for line in reader.lines() {
-        rtn.push(line.unwrap().trim().chars().collect())
+        rtn.push(line?.trim().chars().collect())
     }
 
-    rtn
+    Ok(rtn)
+}
+
+pub fn parse_str_into_char_vector_vector(input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect()
 }
 
 /// The function tries to parse every char to a digit. If the char cannot be converted into a digit
 /// it will be transformed into 0xFF instead
-pub fn parse_into_u8_vector_vector(input_file: &Path) -> Vec<Vec<u8>> {
-    let file = File::open(input_file).unwrap();
+pub fn parse_into_u8_vector_vector(input_file: &Path) -> Result<Vec<Vec<u8>>, AocError> {
+    let file = File::open(input_file)?;
     let reader = BufReader::new(file);
 
     let mut rtn = Vec::new();
 
     for line in reader.lines() {
         rtn.push(
-            line.unwrap()
+            line?
                 .trim()
                 .chars()
                 .map(|i| i.to_digit(10).unwrap_or(0xFF) as u8)
@@ -122,5 +106,18 @@ pub fn parse_into_u8_vector_vector(input_file: &Path) -> Vec<Vec<u8>> {
         )
     }
 
-    rtn
+    Ok(rtn)
+}
+
+/// Same as [parse_into_u8_vector_vector] but for an input that was already read into memory.
+pub fn parse_str_into_u8_vector_vector(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(|line| {
+            line.trim()
+                .chars()
+                .map(|i| i.to_digit(10).unwrap_or(0xFF) as u8)
+                .collect()
+        })
+        .collect()
 }
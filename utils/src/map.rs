@@ -1,68 +1,222 @@
+use crate::grid::Grid;
 use crate::point::MapPoint;
+use crate::sparse_grid::SparseGrid;
 
-#[derive(Debug, Clone, Hash, PartialEq, PartialOrd, Eq, Ord)]
-pub struct Map {
-    pub data: Vec<i64>,
-    pub min_x: i64,
-    pub max_x: i64,
-    pub min_y: i64,
-    pub max_y: i64,
-    pub range_x: i64,
-    pub range_y: i64,
+/// A dense rectangle of cells over signed coordinates whose top left corner can be anywhere.
+///
+/// Reading outside the rectangle gives `None`, writing outside grows it to include the point,
+/// new cells get the fill value of the map.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Map<T> {
+    data: Vec<T>,
+    min: MapPoint,
+    width: usize,
+    height: usize,
+    fill: T,
 }
 
-impl Map {
-    pub fn from_vec_vec(grid: Vec<Vec<i64>>) -> Self {
-        let max_y = grid.len() as i64;
-        let max_x = grid.iter().map(|i| i.len()).max().unwrap() as i64;
-
+impl<T: Clone> Map<T> {
+    /// A map of `width` x `height` cells set to `fill` with `min` as top left corner.
+    pub fn new(min: MapPoint, width: usize, height: usize, fill: T) -> Self {
         Self {
-            data: grid.into_iter().flatten().collect(),
-            min_x: 0,
-            max_x,
-            min_y: 0,
-            max_y,
-            range_x: max_x,
-            range_y: max_y,
+            data: vec![fill.clone(); width * height],
+            min,
+            width,
+            height,
+            fill,
         }
     }
 
-    pub fn new(max_x: i64, max_y: i64) -> Self {
+    /// Takes over the cells of `grid`, its top left cell ends up at `min`.
+    pub fn from_grid(grid: Grid<T>, min: MapPoint, fill: T) -> Self {
+        let (width, height) = (grid.width(), grid.height());
         Self {
-            data: vec![0; (max_x * max_y) as usize],
-            min_x: 0,
-            max_x,
-            min_y: 0,
-            max_y,
-            range_x: max_x,
-            range_y: max_y,
+            data: grid.iter().cloned().collect(),
+            min,
+            width,
+            height,
+            fill,
+        }
+    }
+
+    /// The smallest map around all cells of `sparse`, the rest is `fill`.
+    pub fn from_sparse(sparse: &SparseGrid<T>, fill: T) -> Self {
+        let Some((min, max)) = sparse.bounds() else {
+            return Self::new(MapPoint::default(), 0, 0, fill);
+        };
+        Self::from_grid(sparse.to_grid_area(min, max, fill.clone()), min, fill)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Top left corner.
+    pub fn min(&self) -> MapPoint {
+        self.min
+    }
+
+    /// Bottom right corner, part of the map. Left or above [Map::min] for an empty map.
+    pub fn max(&self) -> MapPoint {
+        MapPoint::new(
+            self.min.x + self.width as i64 - 1,
+            self.min.y + self.height as i64 - 1,
+        )
+    }
+
+    fn index_of(&self, point: &MapPoint) -> Option<usize> {
+        let offset = (*point - self.min).try_cast::<usize>()?;
+        (offset.x < self.width && offset.y < self.height)
+            .then_some(offset.y * self.width + offset.x)
+    }
+
+    pub fn contains(&self, point: &MapPoint) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: &MapPoint) -> Option<&T> {
+        self.index_of(point).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, point: &MapPoint) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.data[index])
+    }
+
+    /// Sets the cell at `point`, growing the map first if it lies outside.
+    pub fn set(&mut self, point: MapPoint, value: T) {
+        self.grow_to_include(&point);
+        let index = self.index_of(&point).unwrap();
+        self.data[index] = value;
+    }
+
+    /// Extends the map with fill cells until `point` is inside.
+    pub fn grow_to_include(&mut self, point: &MapPoint) {
+        if self.contains(point) {
+            return;
+        }
+
+        let (min, max) = if self.data.is_empty() {
+            (*point, *point)
+        } else {
+            let max = self.max();
+            (
+                MapPoint::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+                MapPoint::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        };
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+
+        let mut data = vec![self.fill.clone(); width * height];
+        if !self.data.is_empty() {
+            let shift = (self.min - min).try_cast::<usize>().unwrap();
+            for (y, row) in self.data.chunks(self.width).enumerate() {
+                let start = (y + shift.y) * width + shift.x;
+                data[start..start + row.len()].clone_from_slice(row);
+            }
         }
+
+        self.data = data;
+        self.min = min;
+        self.width = width;
+        self.height = height;
     }
 
-    pub fn get_from_point(&self, point: &MapPoint) -> i64 {
-        self.get(point.x, point.y)
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (MapPoint, &T)> {
+        let (min, width) = (self.min, self.width);
+        self.data.iter().enumerate().map(move |(index, value)| {
+            let point = MapPoint::new((index % width) as i64, (index / width) as i64);
+            (min + point, value)
+        })
     }
 
-    pub fn get(&self, x: i64, y: i64) -> i64 {
-        let mut rtn = y * self.range_y - self.min_y;
-        rtn += x - self.min_x;
-        *self.data.get(rtn as usize).unwrap()
+    /// A copy of the cells, the top left cell is [Map::min].
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_rows(
+            self.data
+                .chunks(self.width.max(1))
+                .map(|row| row.to_vec())
+                .collect(),
+        )
+        .unwrap()
     }
 
-    pub fn set(&mut self, x: i64, y: i64, value: i64) {
-        let mut rtn = y * self.range_y - self.min_y;
-        rtn += x - self.min_x;
-        self.data[rtn as usize] = value;
+    /// Every cell that is not the fill value.
+    pub fn to_sparse(&self) -> SparseGrid<T>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|(_, value)| **value != self.fill)
+            .map(|(point, value)| (point, value.clone()))
+            .collect()
     }
+}
+
+#[cfg(test)]
+mod map_test {
+    use crate::grid::Grid;
+    use crate::map::Map;
+    use crate::point::MapPoint;
+    use crate::sparse_grid::SparseGrid;
 
-    pub fn set_at_point(&mut self, point: &MapPoint, value: i64) {
-        self.set(point.x, point.y, value);
+    #[test]
+    fn test_negative_and_rectangular_extents() {
+        // 5 wide and 2 high, starting at (-2, -7).
+        let mut map = Map::new(MapPoint::new(-2, -7), 5, 2, 0);
+        assert_eq!(map.max(), MapPoint::new(2, -6));
+
+        map.set(MapPoint::new(-2, -7), 1);
+        map.set(MapPoint::new(2, -7), 2);
+        map.set(MapPoint::new(-2, -6), 3);
+        map.set(MapPoint::new(2, -6), 4);
+        assert_eq!(map.get(&MapPoint::new(-2, -7)), Some(&1));
+        assert_eq!(map.get(&MapPoint::new(2, -7)), Some(&2));
+        assert_eq!(map.get(&MapPoint::new(-2, -6)), Some(&3));
+        assert_eq!(map.get(&MapPoint::new(2, -6)), Some(&4));
+        assert_eq!(map.get(&MapPoint::new(3, -6)), None);
+        assert_eq!(map.get(&MapPoint::new(0, -8)), None);
+        assert_eq!(map.iter().filter(|(_, &v)| v != 0).count(), 4);
     }
 
-    pub fn is_point_in_map(&self, point: MapPoint) -> bool {
-        self.is_in_map(point.x, point.y)
+    #[test]
+    fn test_growth() {
+        let mut map = Map::new(MapPoint::new(0, 0), 2, 1, '.');
+        map.set(MapPoint::new(1, 0), 'a');
+        map.set(MapPoint::new(-1, 2), 'b');
+
+        assert_eq!(
+            (map.min(), map.max()),
+            (MapPoint::new(-1, 0), MapPoint::new(1, 2))
+        );
+        assert_eq!(map.get(&MapPoint::new(1, 0)), Some(&'a'));
+        assert_eq!(map.get(&MapPoint::new(-1, 2)), Some(&'b'));
+        assert_eq!(map.to_grid().grid_to_string(), "..a\n...\nb..\n");
+
+        let mut empty = Map::new(MapPoint::new(0, 0), 0, 0, 0);
+        empty.set(MapPoint::new(-4, 9), 7);
+        assert_eq!((empty.width(), empty.height()), (1, 1));
+        assert_eq!(empty.get(&MapPoint::new(-4, 9)), Some(&7));
     }
-    pub fn is_in_map(&self, x: i64, y: i64) -> bool {
-        x >= self.min_x && y >= self.min_y && x < self.max_x && y < self.max_y
+
+    #[test]
+    fn test_conversions() {
+        let grid: Grid<char> = "#.\n.#\n#.\n".parse().unwrap();
+        let map = Map::from_grid(grid.clone(), MapPoint::new(-1, -1), '.');
+        assert_eq!(map.get(&MapPoint::new(0, 0)), Some(&'#'));
+        assert_eq!(map.to_grid(), grid);
+
+        let sparse = map.to_sparse();
+        assert_eq!(sparse.len(), 3);
+        assert!(sparse.contains(&MapPoint::new(-1, 1)));
+
+        let from_sparse = Map::from_sparse(&sparse, '.');
+        assert_eq!(from_sparse, map);
+        assert_eq!(Map::from_sparse(&SparseGrid::new(), '.').width(), 0);
     }
 }
@@ -0,0 +1,420 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Primitive integers, so the functions of this module work for all of them.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The remainder in `0..other.abs()`, also for negative numbers.
+    fn rem_euclid(self, other: Self) -> Self;
+}
+
+/// Integers that can be negative, needed by [extended_gcd] and everything built on it.
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn rem_euclid(self, other: Self) -> Self {
+                    <$t>::rem_euclid(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl SignedInteger for i8 {}
+impl SignedInteger for i16 {}
+impl SignedInteger for i32 {}
+impl SignedInteger for i64 {}
+impl SignedInteger for i128 {}
+impl SignedInteger for isize {}
+
+/// Integers that cannot be negative, used by the digit helpers in [crate::digits].
+pub trait UnsignedInteger: Integer {
+    const TEN: Self;
+
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+    /// Rounded down logarithm, panics for 0 like the methods of the primitive types.
+    fn ilog(self, base: Self) -> u32;
+    fn ilog10(self) -> u32;
+}
+
+macro_rules! impl_unsigned_integer {
+    ($($t:ty),*) => {
+        $(
+            impl UnsignedInteger for $t {
+                const TEN: Self = 10;
+
+                fn checked_pow(self, exponent: u32) -> Option<Self> {
+                    <$t>::checked_pow(self, exponent)
+                }
+
+                fn ilog(self, base: Self) -> u32 {
+                    <$t>::ilog(self, base)
+                }
+
+                fn ilog10(self) -> u32 {
+                    <$t>::ilog10(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_integer!(u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+///
+/// Panics if the result does not fit into `T`, which only happens when it is `-T::MIN`, like
+/// for `gcd(i64::MIN, 0)`. See [checked_gcd].
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("the gcd does not fit into the type")
+}
+
+/// [gcd] that returns `None` instead of panicking if the result does not fit into `T`.
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    if a < T::ZERO {
+        T::ZERO.checked_sub(a)
+    } else {
+        Some(a)
+    }
+}
+
+/// Least common multiple, never negative. `None` if it does not fit into `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let rtn = (a / checked_gcd(a, b)?).checked_mul(b)?;
+    if rtn < T::ZERO {
+        T::ZERO.checked_sub(rtn)
+    } else {
+        Some(rtn)
+    }
+}
+
+/// Least common multiple of all `numbers`, 1 for none.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |rtn, number| lcm(rtn, number))
+}
+
+/// Extended Euclidean algorithm, returns `(g, x, y)` with `a * x + b * y == g` where `g` is the
+/// [gcd] of `a` and `b`. Overflows like [gcd] when `g` would be `-T::MIN`.
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::ONE, T::ZERO);
+    let (mut old_t, mut t) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, `None` if `a` and `modulus` are not
+/// coprime. Panics for a `modulus` of 0.
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    assert!(modulus != T::ZERO, "modulus must not be 0");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// `base` to the power of `exponent` modulo `modulus` by squaring. `None` if an intermediate
+/// product overflows, which can only happen for moduli above the square root of `T::MAX`.
+/// Panics for a `modulus` of 0.
+pub fn mod_pow<T: Integer>(base: T, mut exponent: u64, modulus: T) -> Option<T> {
+    assert!(modulus != T::ZERO, "modulus must not be 0");
+    let mut rtn = T::ONE.rem_euclid(modulus);
+    let mut base = base.rem_euclid(modulus);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            rtn = rtn.checked_mul(base)?.rem_euclid(modulus);
+        }
+        base = base.checked_mul(base)?.rem_euclid(modulus);
+        exponent >>= 1;
+    }
+    Some(rtn)
+}
+
+/// Chinese Remainder Theorem: the smallest `x >= 0` with `x ≡ residue (mod modulus)` for every
+/// pair, together with the combined modulus.
+///
+/// The moduli do not have to be coprime. `None` if the congruences contradict each other or the
+/// combined modulus overflows. Panics if a modulus is not positive.
+pub fn crt<T: SignedInteger>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences.iter().try_fold(
+        (T::ZERO, T::ONE),
+        |(residue, modulus), &(other, other_modulus)| {
+            assert!(other_modulus > T::ZERO, "moduli must be positive");
+            let other = other.rem_euclid(other_modulus);
+            let (g, _, _) = extended_gcd(modulus, other_modulus);
+            let difference = other.checked_sub(residue)?;
+            if difference % g != T::ZERO {
+                return None;
+            }
+
+            // modulus * k ≡ difference (mod other_modulus), solved in the reduced moduli.
+            let reduced = other_modulus / g;
+            let inverse = mod_inverse(modulus / g, reduced)?;
+            let k = (difference / g)
+                .rem_euclid(reduced)
+                .checked_mul(inverse)?
+                .rem_euclid(reduced);
+
+            let combined = (modulus / g).checked_mul(other_modulus)?;
+            let x = residue
+                .checked_add(modulus.checked_mul(k)?)?
+                .rem_euclid(combined);
+            Some((x, combined))
+        },
+    )
+}
+
+/// An exact fraction in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Fraction {
+    /// `None` for a zero denominator.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Some(Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// The value if the fraction is a whole number.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+}
+
+impl Display for Fraction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.to_integer() {
+            Some(integer) => write!(f, "{integer}"),
+            None => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+/// Determinant of a square matrix, computed exactly with the fraction free Bareiss algorithm.
+/// `None` if a step overflows.
+pub fn determinant(matrix: &[Vec<i128>]) -> Option<i128> {
+    let n = matrix.len();
+    let mut m = matrix.to_vec();
+    let mut sign = 1;
+    let mut previous_pivot = 1;
+    for k in 0..n.saturating_sub(1) {
+        if m[k][k] == 0 {
+            let Some(swap) = (k + 1..n).find(|&i| m[i][k] != 0) else {
+                return Some(0);
+            };
+            m.swap(k, swap);
+            sign = -sign;
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let value = m[i][j]
+                    .checked_mul(m[k][k])?
+                    .checked_sub(m[i][k].checked_mul(m[k][j])?)?;
+                m[i][j] = value / previous_pivot;
+            }
+        }
+        previous_pivot = m[k][k];
+    }
+    match n {
+        0 => Some(1),
+        _ => m[n - 1][n - 1].checked_mul(sign),
+    }
+}
+
+/// Solves `matrix * x = rhs` exactly with Cramer's rule. `None` if there is no unique solution
+/// or the arithmetic overflows.
+pub fn solve_linear_system(matrix: &[Vec<i64>], rhs: &[i64]) -> Option<Vec<Fraction>> {
+    let n = rhs.len();
+    if matrix.len() != n || matrix.iter().any(|row| row.len() != n) {
+        return None;
+    }
+
+    let widened: Vec<Vec<i128>> = matrix
+        .iter()
+        .map(|row| row.iter().map(|&v| v as i128).collect())
+        .collect();
+    let denominator = determinant(&widened)?;
+    if denominator == 0 {
+        return None;
+    }
+
+    (0..n)
+        .map(|column| {
+            let mut replaced = widened.clone();
+            for (row, &value) in replaced.iter_mut().zip(rhs) {
+                row[column] = value as i128;
+            }
+            Fraction::new(determinant(&replaced)?, denominator)
+        })
+        .collect()
+}
+
+/// [solve_linear_system] for the common case of two equations
+/// `matrix[0][0] * x + matrix[0][1] * y = rhs[0]` and `matrix[1][0] * x + matrix[1][1] * y = rhs[1]`.
+pub fn solve_2x2(matrix: [[i64; 2]; 2], rhs: [i64; 2]) -> Option<[Fraction; 2]> {
+    let [[a, b], [c, d]] = matrix.map(|row| row.map(|v| v as i128));
+    let [e, f] = rhs.map(|v| v as i128);
+    let denominator = a.checked_mul(d)?.checked_sub(b.checked_mul(c)?)?;
+    if denominator == 0 {
+        return None;
+    }
+    let x = e.checked_mul(d)?.checked_sub(b.checked_mul(f)?)?;
+    let y = a.checked_mul(f)?.checked_sub(e.checked_mul(c)?)?;
+    Some([
+        Fraction::new(x, denominator)?,
+        Fraction::new(y, denominator)?,
+    ])
+}
+
+#[cfg(test)]
+mod math_test {
+    use crate::math::{
+        checked_gcd, crt, determinant, extended_gcd, gcd, lcm, lcm_all, mod_inverse, mod_pow,
+        solve_2x2, solve_linear_system, Fraction,
+    };
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(48u32, 18), 6);
+        assert_eq!(gcd(-48i64, 18), 6);
+        assert_eq!(gcd(0usize, 0), 0);
+        assert_eq!(lcm(4u8, 6), Some(12));
+        assert_eq!(lcm(-4i32, 6), Some(12));
+        assert_eq!(lcm(200u8, 3), None);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+        assert_eq!(checked_gcd(i64::MIN, 6), Some(2));
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(lcm(i64::MIN, i64::MIN), None);
+
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(6i64, 9), None);
+        assert_eq!(mod_pow(4u64, 13, 497), Some(445));
+        assert_eq!(mod_pow(2u64, 10, 1), Some(0));
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the gcd does not fit")]
+    fn test_gcd_overflow() {
+        gcd(i64::MIN, i64::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must not be 0")]
+    fn test_mod_pow_zero_modulus() {
+        mod_pow(2u64, 10, 0);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli that share a factor.
+        assert_eq!(crt(&[(2i64, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "moduli must be positive")]
+    fn test_crt_zero_modulus() {
+        crt(&[(2i64, 3), (1, 0)]);
+    }
+
+    #[test]
+    fn test_linear_systems() {
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        let [a, b] = solve_2x2([[94, 22], [34, 67]], [8400, 5400]).unwrap();
+        assert_eq!((a.to_integer(), b.to_integer()), (Some(80), Some(40)));
+
+        let [x, _] = solve_2x2([[2, 0], [0, 1]], [1, 1]).unwrap();
+        assert_eq!(x, Fraction::new(1, 2).unwrap());
+        assert_eq!(x.to_string(), "1/2");
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), None);
+
+        assert_eq!(
+            determinant(&[vec![0, 2, 1], vec![3, 1, 0], vec![1, 1, 1]]),
+            Some(-4)
+        );
+        let solution = solve_linear_system(
+            &[vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]],
+            &[8, -11, -3],
+        )
+        .unwrap();
+        let solution: Vec<Option<i128>> = solution.iter().map(|f| f.to_integer()).collect();
+        assert_eq!(solution, [Some(2), Some(3), Some(-1)]);
+    }
+}
@@ -0,0 +1,274 @@
+//! Helpers for the shapes most inputs come in. All of them keep the [InputLine]s, so errors point
+//! at the line and column that could not be parsed.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::{input_lines, unexpected_end, AocError, InputLine};
+use crate::grid::Grid;
+
+/// The blocks of `input` that are separated by blank lines. Several blank lines in a row count as
+/// one separator.
+pub fn sections(input: &str) -> Vec<Vec<InputLine<'_>>> {
+    let mut rtn = Vec::new();
+    let mut current = Vec::new();
+    for line in input_lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                rtn.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        rtn.push(current);
+    }
+    rtn
+}
+
+/// Exactly `N` sections, see [sections]. `names` describe the sections for the error if one is
+/// missing.
+pub fn split_sections<'a, const N: usize>(
+    input: &'a str,
+    names: [&str; N],
+) -> Result<[Vec<InputLine<'a>>; N], AocError> {
+    let mut sections = sections(input).into_iter();
+    let mut rtn: [Vec<InputLine<'a>>; N] = std::array::from_fn(|_| Vec::new());
+    for (section, name) in rtn.iter_mut().zip(names) {
+        *section = sections.next().ok_or_else(|| unexpected_end(input, name))?;
+    }
+    if let Some(extra) = sections.next() {
+        let line = extra[0];
+        return Err(line.error(line.text, format!("expected only {N} sections")));
+    }
+    Ok(rtn)
+}
+
+/// A grid of chars in the first section and the lines of all sections after it, like a map
+/// followed by a list of moves.
+pub fn grid_and_trailer(input: &str) -> Result<(Grid<char>, Vec<InputLine<'_>>), AocError> {
+    let mut sections = sections(input).into_iter();
+    let grid_lines = sections
+        .next()
+        .ok_or_else(|| unexpected_end(input, "grid"))?;
+
+    let rows: Vec<Vec<char>> = grid_lines
+        .iter()
+        .map(|line| line.text.trim().chars().collect())
+        .collect();
+    if let Some(line) = grid_lines
+        .iter()
+        .find(|line| line.text.trim().chars().count() != rows[0].len())
+    {
+        return Err(line.error(
+            line.text.trim(),
+            format!("expected a row of {} cells", rows[0].len()),
+        ));
+    }
+
+    Ok((Grid::from_rows(rows)?, sections.flatten().collect()))
+}
+
+/// Fails unless every char of `markers` is somewhere in `grid`, like the start and the end of a
+/// maze, so the parts can rely on finding them.
+pub fn require_markers(input: &str, grid: &Grid<char>, markers: &[char]) -> Result<(), AocError> {
+    match markers
+        .iter()
+        .find(|marker| grid.find_first(marker).is_none())
+    {
+        Some(marker) => Err(unexpected_end(input, &format!("'{marker}' in the grid"))),
+        None => Ok(()),
+    }
+}
+
+/// Fails at the first char of the grid in `input` that is not one of `allowed`, so the parts
+/// only see the chars they know.
+pub fn require_chars(input: &str, allowed: &[char]) -> Result<(), AocError> {
+    for line in input_lines(input) {
+        let text = line.text.trim();
+        let start = line.position_of(text).column;
+        if let Some((i, found)) = text.chars().enumerate().find(|(_, c)| !allowed.contains(c)) {
+            return Err(AocError::UnexpectedChar {
+                found,
+                position: Some(line.position(start + i)),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The integers in `text` in order, everything in between is skipped. A `-` right before the
+/// digits is a sign unless it follows a digit, so `x=-3` is `-3` but `1-3` is `1` and `3`.
+pub fn integer_tokens(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut rtn = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let is_signed = i > 0 && bytes[i - 1] == b'-' && (i < 2 || !bytes[i - 2].is_ascii_digit());
+        let start = if is_signed { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        rtn.push(&text[start..i]);
+    }
+    rtn
+}
+
+/// Parses every integer of the line, see [integer_tokens].
+pub fn integers<T>(line: &InputLine) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    integer_tokens(line.text)
+        .into_iter()
+        .map(|token| line.parse(token))
+        .collect()
+}
+
+/// Exactly `N` integers of the line, see [integers].
+pub fn integers_exact<T, const N: usize>(line: &InputLine) -> Result<[T; N], AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let numbers: Vec<T> = integers(line)?;
+    let found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| line.error(line.text, format!("expected {N} numbers, found {found}")))
+}
+
+/// Splits a `key: value` line, or a line with any other `separator`. Both sides are trimmed.
+pub fn key_value<'a>(
+    line: &InputLine<'a>,
+    separator: &str,
+) -> Result<(&'a str, &'a str), AocError> {
+    let (key, value) = line
+        .text
+        .split_once(separator)
+        .ok_or_else(|| line.error(line.text, format!("expected '{separator}'")))?;
+    Ok((key.trim(), value.trim()))
+}
+
+/// Parses both sides of a pair like `47|53` or `2-4`.
+pub fn pair<A, B>(line: &InputLine, separator: &str) -> Result<(A, B), AocError>
+where
+    A: FromStr,
+    A::Err: Display,
+    B: FromStr,
+    B::Err: Display,
+{
+    let (left, right) = key_value(line, separator)?;
+    Ok((line.parse(left)?, line.parse(right)?))
+}
+
+/// Parses a list like `75,47,61`. `text` has to be a slice of the line, like the value of
+/// [key_value], so errors point at the right column.
+pub fn list<T>(line: &InputLine, text: &str, separator: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.split(separator)
+        .map(|item| line.parse(item.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod parse_test {
+    use crate::error::{input_lines, AocError, Position};
+    use crate::parse::{
+        grid_and_trailer, integer_tokens, integers, integers_exact, key_value, list, pair,
+        require_chars, require_markers, sections, split_sections,
+    };
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\n\nc\n\nd\n";
+        let found: Vec<Vec<usize>> = sections(input)
+            .iter()
+            .map(|s| s.iter().map(|l| l.number).collect())
+            .collect();
+        assert_eq!(found, [vec![1, 2], vec![5], vec![7]]);
+
+        let [rules, updates] = split_sections("1|2\n\n1,2\n", ["rules", "updates"]).unwrap();
+        assert_eq!((rules[0].text, updates[0].text), ("1|2", "1,2"));
+        assert!(split_sections("1|2\n", ["rules", "updates"]).is_err());
+        assert!(split_sections("a\n\nb\n\nc\n", ["a", "b"]).is_err());
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(
+            integer_tokens("p=0,4 v=3,-3 x-5 7-9"),
+            ["0", "4", "3", "-3", "-5", "7", "9"]
+        );
+
+        let line = input_lines("\nButton A: X+94, Y-34").nth(1).unwrap();
+        let numbers: Vec<i64> = integers(&line).unwrap();
+        assert_eq!(numbers, [94, -34]);
+        let [x, y]: [i64; 2] = integers_exact(&line).unwrap();
+        assert_eq!((x, y), (94, -34));
+
+        match integers_exact::<i64, 3>(&line) {
+            Err(AocError::Parse { position, .. }) => {
+                assert_eq!(position, Position { line: 2, column: 1 })
+            }
+            _ => panic!("expected a parse error"),
+        }
+        assert!(integers::<u64>(&line).is_err());
+    }
+
+    #[test]
+    fn test_pairs_and_lists() {
+        let line = input_lines("Program: 2,4,x").next().unwrap();
+        let (key, value) = key_value(&line, ":").unwrap();
+        assert_eq!((key, value), ("Program", "2,4,x"));
+        match list::<u8>(&line, value, ",") {
+            Err(AocError::Parse { position, .. }) => assert_eq!(position.column, 14),
+            _ => panic!("expected a parse error"),
+        }
+
+        let line = input_lines("47|53").next().unwrap();
+        assert_eq!(pair::<u8, u8>(&line, "|").unwrap(), (47, 53));
+        assert!(pair::<u8, u8>(&line, "-").is_err());
+    }
+
+    #[test]
+    fn test_grid_and_trailer() {
+        let (grid, trailer) = grid_and_trailer("#.#\n.@.\n\n<^\nv>\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(trailer.iter().map(|l| l.number).collect::<Vec<_>>(), [4, 5]);
+
+        match grid_and_trailer("#.#\n.@\n\n<^\n") {
+            Err(AocError::Parse { position, .. }) => assert_eq!(position.line, 2),
+            _ => panic!("expected a parse error"),
+        }
+
+        let input = "#.#\n.@.\n\n<^\nv>\n";
+        assert!(require_markers(input, &grid, &['@', '#']).is_ok());
+        match require_markers(input, &grid, &['@', 'E']) {
+            Err(AocError::Parse { message, .. }) => assert_eq!(message, "missing 'E' in the grid"),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_require_chars() {
+        assert!(require_chars("#.^\n..#\n", &['.', '#', '^']).is_ok());
+        match require_chars("#.^\n .x#\n", &['.', '#', '^']) {
+            Err(AocError::UnexpectedChar { found, position }) => {
+                assert_eq!(found, 'x');
+                assert_eq!(position, Some(Position { line: 2, column: 3 }));
+            }
+            _ => panic!("expected an unexpected char"),
+        }
+    }
+}
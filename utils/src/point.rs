@@ -1,28 +1,9 @@
-use crate::{map_direction::MapDirection, utils::manhatten_distance};
+use crate::{map_direction::MapDirection, point2::Point2};
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord, Default)]
-pub struct MapPoint {
-    pub x: i64,
-    pub y: i64,
-}
+/// A position on a map without bounds, coordinates can be negative.
+pub type MapPoint = Point2<i64>;
 
 impl MapPoint {
-    pub fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-
-    pub fn diff(&self, other: &Self) -> (i64, i64) {
-        let x = self.x - other.x;
-        let y = self.y - other.y;
-        (x, y)
-    }
-
-    pub fn add(&self, other_x: i64, other_y: i64) -> (i64, i64) {
-        let x = self.x - other_x;
-        let y = self.y - other_y;
-        (x, y)
-    }
-
     pub fn move_by_direction(&mut self, direction: &MapDirection) {
         match direction {
             MapDirection::Up => self.move_up(),
@@ -86,39 +67,19 @@ impl<T> MapInfoWalker<T> {
 
 impl MapWalker {
     pub fn r#move(&mut self) {
-        match self.direction {
-            MapDirection::Up => self.position.move_up(),
-            MapDirection::Right => self.position.move_right(),
-            MapDirection::Down => self.position.move_down(),
-            MapDirection::Left => self.position.move_left(),
-        };
+        self.position += self.direction.unit_vector();
     }
 
     pub fn turn_left(&mut self) {
-        self.direction = match self.direction {
-            MapDirection::Up => MapDirection::Left,
-            MapDirection::Right => MapDirection::Up,
-            MapDirection::Down => MapDirection::Right,
-            MapDirection::Left => MapDirection::Down,
-        }
+        self.direction.turn_left();
     }
 
     pub fn turn_right(&mut self) {
-        self.direction = match self.direction {
-            MapDirection::Up => MapDirection::Right,
-            MapDirection::Right => MapDirection::Down,
-            MapDirection::Down => MapDirection::Left,
-            MapDirection::Left => MapDirection::Up,
-        }
+        self.direction.turn_right();
     }
 
     pub fn turn_around(&mut self) {
-        self.direction = match self.direction {
-            MapDirection::Up => MapDirection::Down,
-            MapDirection::Right => MapDirection::Left,
-            MapDirection::Down => MapDirection::Up,
-            MapDirection::Left => MapDirection::Right,
-        }
+        self.direction.turn_around();
     }
 
     pub fn move_up(&mut self) {
@@ -251,14 +212,6 @@ impl MapPoint {
         self.y -= 1;
     }
 
-    pub fn manhatten_distance_from_x_y(&self, x: i64, y: i64) -> i64 {
-        manhatten_distance(self.x, self.y, x, y)
-    }
-
-    pub fn manhatten_distance(&self, point: &Self) -> i64 {
-        manhatten_distance(self.x, self.y, point.x, point.y)
-    }
-
     pub fn next_up(&self) -> MapPoint {
         Self {
             x: self.x,
@@ -0,0 +1,242 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or an offset on a plane. [GridPoint](crate::grid_point::GridPoint) is the
+/// `usize` version used to index grids, [MapPoint](crate::point::MapPoint) the `i64` version
+/// for maps that grow in every direction.
+///
+/// `y` grows downwards like the lines of an input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Number types that can be used as coordinates of a [Point2].
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> Self {
+                    self.max(other) - self.min(other)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts both coordinates, `None` if one of them does not fit into `U`, like a negative
+    /// coordinate into `usize`.
+    pub fn try_cast<U>(self) -> Option<Point2<U>>
+    where
+        U: TryFrom<T>,
+    {
+        Some(Point2::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+
+    /// Converts both coordinates into a type that can hold every value of `T`.
+    pub fn cast<U>(self) -> Point2<U>
+    where
+        U: From<T>,
+    {
+        Point2::new(U::from(self.x), U::from(self.y))
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of king moves between both points.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The points above, right, below and left of this one, leaving out those that would
+    /// overflow the coordinate type.
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> {
+        let Point2 { x, y } = *self;
+        [
+            (Some(x), y.checked_sub(T::ONE)),
+            (x.checked_add(T::ONE), Some(y)),
+            (Some(x), y.checked_add(T::ONE)),
+            (x.checked_sub(T::ONE), Some(y)),
+        ]
+        .into_iter()
+        .filter_map(|(x, y)| Some(Point2::new(x?, y?)))
+    }
+
+    /// All eight surrounding points clockwise starting top left, leaving out those that would
+    /// overflow the coordinate type.
+    pub fn neighbors_with_diagonals(&self) -> impl Iterator<Item = Self> {
+        let Point2 { x, y } = *self;
+        let (left, right) = (x.checked_sub(T::ONE), x.checked_add(T::ONE));
+        let (up, down) = (y.checked_sub(T::ONE), y.checked_add(T::ONE));
+        [
+            (left, up),
+            (Some(x), up),
+            (right, up),
+            (right, Some(y)),
+            (right, down),
+            (Some(x), down),
+            (left, down),
+            (left, Some(y)),
+        ]
+        .into_iter()
+        .filter_map(|(x, y)| Some(Point2::new(x?, y?)))
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Point2<T> {
+    /// Turns the point as a vector by 90° clockwise around the origin, up becomes right.
+    pub fn rotate_cw(self) -> Self {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Turns the point as a vector by 90° counterclockwise around the origin, up becomes left.
+    pub fn rotate_ccw(self) -> Self {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod point2_test {
+    use crate::grid_point::GridPoint;
+    use crate::point::MapPoint;
+    use crate::point2::Point2;
+
+    #[test]
+    fn test_operators() {
+        let a = MapPoint::new(3, -2);
+        let b = MapPoint::new(1, 5);
+        assert_eq!(a + b, MapPoint::new(4, 3));
+        assert_eq!(a - b, MapPoint::new(2, -7));
+        assert_eq!(a * 3, MapPoint::new(9, -6));
+        assert_eq!(-a, MapPoint::new(-3, 2));
+
+        let mut c = GridPoint::new(1, 1);
+        c += GridPoint::new(2, 3);
+        assert_eq!(c, GridPoint::new(3, 4));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let map = MapPoint::new(4, 7);
+        assert_eq!(map.try_cast::<usize>(), Some(GridPoint::new(4, 7)));
+        assert_eq!(MapPoint::new(-1, 7).try_cast::<usize>(), None);
+        assert_eq!(Point2::<u8>::new(2, 9).cast::<i64>(), MapPoint::new(2, 9));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let up = MapPoint::new(0, -1);
+        assert_eq!(up.rotate_cw(), MapPoint::new(1, 0));
+        assert_eq!(up.rotate_ccw(), MapPoint::new(-1, 0));
+        assert_eq!(up.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), up);
+        assert_eq!(
+            MapPoint::new(2, 1).rotate_cw().rotate_ccw(),
+            MapPoint::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_distances_and_neighbors() {
+        let a = GridPoint::new(1, 5);
+        let b = GridPoint::new(4, 1);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+
+        let corner = GridPoint::new(0, 0);
+        assert_eq!(
+            corner.neighbors().collect::<Vec<_>>(),
+            [GridPoint::new(1, 0), GridPoint::new(0, 1)]
+        );
+        assert_eq!(corner.neighbors_with_diagonals().count(), 3);
+        assert_eq!(MapPoint::new(0, 0).neighbors_with_diagonals().count(), 8);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::grid::{Adjacency, Grid};
+use crate::grid_direction::{GridCorner, GridCornerPosition};
+use crate::grid_point::GridPoint;
+
+/// A group of cells connected up, right, down or left, see [find_regions].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Index in [Regions::regions] and label in [Regions::labels].
+    pub id: usize,
+    /// The cells in the order the flood fill found them, starting with the first in reading order.
+    pub cells: Vec<GridPoint>,
+    /// Number of cell edges that border on another region or the outside of the grid.
+    pub perimeter: usize,
+    /// Every corner of the outline, inner corners of holes and bays included.
+    pub corners: Vec<GridCornerPosition>,
+    /// Top left corner of the bounding box.
+    pub min: GridPoint,
+    /// Bottom right corner of the bounding box, included in the box.
+    pub max: GridPoint,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    pub fn corner_count(&self) -> usize {
+        self.corners.len()
+    }
+
+    /// Number of straight fence sections around the region. A closed outline has as many sides
+    /// as corners.
+    pub fn sides(&self) -> usize {
+        self.corners.len()
+    }
+
+    pub fn bounding_box(&self) -> (GridPoint, GridPoint) {
+        (self.min, self.max)
+    }
+}
+
+/// All regions of a grid and which region every cell belongs to.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// The id of the region of every cell.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn region_of(&self, point: &GridPoint) -> Option<&Region> {
+        self.labels
+            .get_from_point(point)
+            .map(|&id| &self.regions[id])
+    }
+}
+
+/// Splits `grid` into regions. Two neighbouring cells end up in the same region if `connected`
+/// holds for their values, usually `|a, b| a == b`.
+pub fn find_regions<T>(grid: &Grid<T>, connected: impl Fn(&T, &T) -> bool) -> Regions {
+    const UNLABELED: usize = usize::MAX;
+
+    let mut labels = Grid::with_width_height(grid.width(), grid.height(), UNLABELED);
+    let mut cells_of_regions = Vec::new();
+
+    for (start, _) in grid.iter_points() {
+        if labels[start] != UNLABELED {
+            continue;
+        }
+
+        let id = cells_of_regions.len();
+        let mut cells = Vec::new();
+        let mut queue = vec![start];
+        labels[start] = id;
+        while let Some(position) = queue.pop() {
+            cells.push(position);
+            for neighbor in grid.neighbors(&position, Adjacency::FourWay) {
+                if labels[neighbor] == UNLABELED && connected(&grid[position], &grid[neighbor]) {
+                    labels[neighbor] = id;
+                    queue.push(neighbor);
+                }
+            }
+        }
+        cells_of_regions.push(cells);
+    }
+
+    let regions = cells_of_regions
+        .into_iter()
+        .enumerate()
+        .map(|(id, cells)| measure(&labels, id, cells))
+        .collect();
+
+    Regions { labels, regions }
+}
+
+fn measure(labels: &Grid<usize>, id: usize, cells: Vec<GridPoint>) -> Region {
+    let inside = |x: Option<usize>, y: Option<usize>| match (x, y) {
+        (Some(x), Some(y)) => labels.get(x, y) == Some(&id),
+        _ => false,
+    };
+
+    let mut perimeter = 0;
+    let mut corners = Vec::new();
+    let mut min = cells[0];
+    let mut max = cells[0];
+    for &position in &cells {
+        min = GridPoint::new(min.x.min(position.x), min.y.min(position.y));
+        max = GridPoint::new(max.x.max(position.x), max.y.max(position.y));
+
+        let (x, y) = (Some(position.x), Some(position.y));
+        let (left, right) = (position.x.checked_sub(1), position.x.checked_add(1));
+        let (up, down) = (position.y.checked_sub(1), position.y.checked_add(1));
+
+        let [has_up, has_right, has_down, has_left] =
+            [(x, up), (right, y), (x, down), (left, y)].map(|(x, y)| inside(x, y));
+        perimeter += [has_up, has_right, has_down, has_left]
+            .iter()
+            .filter(|&&has| !has)
+            .count();
+
+        // A corner is either convex, both sides are outside, or concave, both sides are inside
+        // but the diagonal is not.
+        for (corner, first, second, diagonal) in [
+            (GridCorner::TopLeft, has_up, has_left, (left, up)),
+            (GridCorner::TopRight, has_up, has_right, (right, up)),
+            (GridCorner::DownRight, has_down, has_right, (right, down)),
+            (GridCorner::DownLeft, has_down, has_left, (left, down)),
+        ] {
+            let convex = !first && !second;
+            let concave = first && second && !inside(diagonal.0, diagonal.1);
+            if convex || concave {
+                corners.push(GridCornerPosition { corner, position });
+            }
+        }
+    }
+
+    Region {
+        id,
+        cells,
+        perimeter,
+        corners,
+        min,
+        max,
+    }
+}
+
+#[cfg(test)]
+mod regions_test {
+    use crate::grid::Grid;
+    use crate::grid_point::GridPoint;
+    use crate::regions::find_regions;
+
+    #[test]
+    fn test_find_regions() {
+        let grid: Grid<char> = "AAAA\nBBCD\nBBCC\nEEEC\n".parse().unwrap();
+        let regions = find_regions(&grid, |a, b| a == b);
+
+        let measures: Vec<(usize, usize, usize)> = regions
+            .regions()
+            .iter()
+            .map(|r| (r.area(), r.perimeter(), r.sides()))
+            .collect();
+        assert_eq!(
+            measures,
+            [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+
+        let c = regions.region_of(&GridPoint::new(3, 3)).unwrap();
+        assert_eq!(
+            c.bounding_box(),
+            (GridPoint::new(2, 1), GridPoint::new(3, 3))
+        );
+    }
+
+    #[test]
+    fn test_holes_and_diagonals() {
+        // Every X is a hole in the O region, and the A and B cells only touch diagonally.
+        let grid: Grid<char> = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n".parse().unwrap();
+        let regions = find_regions(&grid, |a, b| a == b);
+        let outer = &regions.regions()[0];
+        assert_eq!(
+            (outer.area(), outer.perimeter(), outer.sides()),
+            (21, 36, 20)
+        );
+
+        let diagonal: Grid<char> = "AB\nBA\n".parse().unwrap();
+        let regions = find_regions(&diagonal, |a, b| a == b);
+        assert_eq!(regions.regions().len(), 4);
+    }
+}
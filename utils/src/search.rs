@@ -0,0 +1,306 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Everything a search found out: the distance of every reached node, all optimal predecessors
+/// of every node and the goals that were reached at the lowest cost.
+///
+/// Nodes are stored once and referred to by their index, so a node only needs to be `Eq`, `Hash`
+/// and `Clone`.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    distances: Vec<usize>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<N> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+{
+    fn new(start: N) -> Self {
+        let mut rtn = SearchResult {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            distances: Vec::new(),
+            predecessors: Vec::new(),
+            goals: Vec::new(),
+        };
+        rtn.insert(start, 0);
+        rtn
+    }
+
+    fn insert(&mut self, node: N, distance: usize) -> usize {
+        let id = self.nodes.len();
+        self.index.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.distances.push(distance);
+        self.predecessors.push(Vec::new());
+        id
+    }
+
+    /// Records the edge `from` -> `to` with the total cost `distance` if it is at least as good as
+    /// the best one so far. Returns the id of `to` if the edge is better.
+    fn relax(&mut self, from: usize, to: N, distance: usize) -> Option<usize> {
+        match self.index.get(&to) {
+            None => {
+                let id = self.insert(to, distance);
+                self.predecessors[id].push(from);
+                Some(id)
+            }
+            Some(&id) if distance < self.distances[id] => {
+                self.distances[id] = distance;
+                self.predecessors[id] = vec![from];
+                Some(id)
+            }
+            Some(&id) => {
+                if distance == self.distances[id] && !self.predecessors[id].contains(&from) {
+                    self.predecessors[id].push(from);
+                }
+                None
+            }
+        }
+    }
+
+    /// Distance from the start to `node`, `None` if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.index.get(node).map(|&id| self.distances[id])
+    }
+
+    /// All reached nodes with their distance from the start.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.nodes.iter().zip(self.distances.iter().copied())
+    }
+
+    /// All nodes `node` is reached from on some optimal path, empty for the start.
+    pub fn predecessors(&self, node: &N) -> Vec<&N> {
+        self.index.get(node).map_or(Vec::new(), |&id| {
+            self.predecessors[id]
+                .iter()
+                .map(|&p| &self.nodes[p])
+                .collect()
+        })
+    }
+
+    /// The goals that were reached at the lowest cost.
+    pub fn goals(&self) -> impl Iterator<Item = &N> {
+        self.goals.iter().map(|&id| &self.nodes[id])
+    }
+
+    /// Cost of the cheapest goal, `None` if no goal was reached.
+    pub fn cost(&self) -> Option<usize> {
+        self.goals.first().map(|&id| self.distances[id])
+    }
+
+    /// One optimal path from the start to the first goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goals.first().map(|&id| self.path_from_id(id))
+    }
+
+    /// One optimal path from the start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.index.get(node).map(|&id| self.path_from_id(id))
+    }
+
+    fn path_from_id(&self, mut id: usize) -> Vec<N> {
+        let mut rtn = vec![self.nodes[id].clone()];
+        while let Some(&previous) = self.predecessors[id].first() {
+            rtn.push(self.nodes[previous].clone());
+            id = previous;
+        }
+        rtn.reverse();
+        rtn
+    }
+
+    /// Number of different optimal paths from the start to `node`, 0 if it was not reached.
+    /// Needs edges that cost more than 0.
+    pub fn path_count(&self, node: &N) -> usize {
+        let Some(&target) = self.index.get(node) else {
+            return 0;
+        };
+
+        let mut ids: Vec<usize> = (0..self.nodes.len())
+            .filter(|&id| self.distances[id] <= self.distances[target])
+            .collect();
+        ids.sort_by_key(|&id| self.distances[id]);
+
+        let mut counts = vec![0; self.nodes.len()];
+        counts[0] = 1;
+        for id in ids.into_iter().skip(1) {
+            counts[id] = self.predecessors[id].iter().map(|&p| counts[p]).sum();
+        }
+        counts[target]
+    }
+
+    /// Every node that lies on any optimal path from the start to one of the goals.
+    pub fn nodes_on_optimal_paths(&self) -> HashSet<&N> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut queue = self.goals.clone();
+        while let Some(id) = queue.pop() {
+            if seen[id] {
+                continue;
+            }
+            seen[id] = true;
+            queue.extend(&self.predecessors[id]);
+        }
+
+        seen.iter()
+            .enumerate()
+            .filter(|(_, &on_path)| on_path)
+            .map(|(id, _)| &self.nodes[id])
+            .collect()
+    }
+}
+
+/// Breadth first search where every edge costs 1.
+///
+/// Stops once all nodes as far away as the nearest goal are done. With a goal that is never
+/// reached it visits everything reachable from `start`.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(id) = queue.pop_front() {
+        let distance = result.distances[id];
+        if result.cost().is_some_and(|cost| distance > cost) {
+            break;
+        }
+        if is_goal(&result.nodes[id]) {
+            result.goals.push(id);
+            continue;
+        }
+
+        for next in successors(&result.nodes[id]) {
+            if let Some(next_id) = result.relax(id, next, distance + 1) {
+                queue.push_back(next_id);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm, `successors` returns the neighbours of a node with the cost to get
+/// there.
+///
+/// Like [bfs] it keeps going until every node as cheap as the best goal is done, so
+/// [SearchResult::nodes_on_optimal_paths] sees all ties.
+pub fn dijkstra<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search, Dijkstra's algorithm guided by `heuristic`.
+///
+/// The heuristic has to be consistent: it never overestimates the remaining cost, and it never
+/// drops by more than the cost of an edge. The manhattan distance on a grid is a typical one.
+pub fn astar<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut result = SearchResult::new(start);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&result.nodes[0]), 0, 0))]);
+    let mut done = HashSet::new();
+
+    while let Some(Reverse((estimate, distance, id))) = queue.pop() {
+        if distance > result.distances[id] || !done.insert(id) {
+            continue;
+        }
+        if result.cost().is_some_and(|cost| estimate > cost) {
+            break;
+        }
+        if is_goal(&result.nodes[id]) {
+            result.goals.push(id);
+            continue;
+        }
+
+        for (next, cost) in successors(&result.nodes[id]) {
+            let next_distance = distance + cost;
+            let estimate = next_distance + heuristic(&next);
+            if let Some(next_id) = result.relax(id, next, next_distance) {
+                queue.push(Reverse((estimate, next_distance, next_id)));
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod search_test {
+    use crate::search::{astar, bfs, dijkstra};
+
+    /// A diamond `0 -> 1 -> 3` and `0 -> 2 -> 3` with a dead end `1 -> 4`.
+    fn diamond(node: &u8) -> Vec<(u8, usize)> {
+        match node {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 2), (4, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(0, |n| diamond(n).into_iter().map(|(n, _)| n), |&n| n == 3);
+        assert_eq!(result.cost(), Some(2));
+        assert_eq!(result.path().unwrap(), [0, 1, 3]);
+        assert_eq!(result.nodes_on_optimal_paths().len(), 4);
+
+        let everything = bfs(0, |n| diamond(n).into_iter().map(|(n, _)| n), |_| false);
+        assert_eq!(everything.distance(&4), Some(2));
+        assert_eq!(everything.cost(), None);
+    }
+
+    #[test]
+    fn test_dijkstra_all_optimal_paths() {
+        let result = dijkstra(0, diamond, |&n| n == 3);
+        assert_eq!(result.cost(), Some(3));
+        assert_eq!(result.predecessors(&3), [&1, &2]);
+        assert_eq!(result.path_count(&3), 2);
+        let mut on_path: Vec<u8> = result
+            .nodes_on_optimal_paths()
+            .into_iter()
+            .copied()
+            .collect();
+        on_path.sort();
+        assert_eq!(on_path, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_astar_on_a_line() {
+        let result = astar(
+            0i32,
+            |&n| [(n - 1, 1), (n + 1, 1)],
+            |&n| 10i32.abs_diff(n) as usize,
+            |&n| n == 10,
+        );
+        assert_eq!(result.cost(), Some(10));
+        assert_eq!(result.path().unwrap(), (0..=10).collect::<Vec<_>>());
+        assert_eq!(result.distance(&-1), Some(1));
+        assert_eq!(result.distance(&-2), None);
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::answers::{get_expected_answer, get_parameters};
+use crate::error::AocError;
+
+/// A single day of the advent calendar.
+///
+/// The input is parsed once and both parts are solved from the parsed input, returning their
+/// answers instead of printing them. Parsing fails with an [AocError] on malformed input.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    /// Parses `input` for a puzzle that differs in more than the input, like the size of the grid
+    /// of an example. Days that need [Parameters] override this and parse with the values of the
+    /// real inputs in [Solution::parse].
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, AocError> {
+        let _ = parameters;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Answer for a part that has nothing to solve, like the second part of the last day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Answer of a part that can come up empty, like a search that gives up. Shows like [NoAnswer]
+/// when there is none, so [assert_variants_agree] skips it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => NoAnswer.fmt(f),
+        }
+    }
+}
+
+/// Named values of a puzzle that are not part of its input, like the size of the grid or a
+/// threshold that the examples set lower than the real inputs. They are set next to the expected
+/// answers, see [get_parameters], and every day falls back to the values of the real inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameters(HashMap<String, String>);
+
+impl Parameters {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.0.insert(name.to_string(), value.to_string());
+    }
+
+    /// Parses the parameter `name`, or returns `default` if it is not set.
+    pub fn get_or<T>(&self, name: &str, default: T) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.0.get(name) {
+            None => Ok(default),
+            Some(value) => value
+                .parse()
+                .map_err(|e: T::Err| AocError::InvalidParameter {
+                    name: name.to_string(),
+                    value: value.clone(),
+                    message: e.to_string(),
+                }),
+        }
+    }
+}
+
+pub fn read_input(input_file: &Path) -> Result<String, AocError> {
+    Ok(fs::read_to_string(input_file)?)
+}
+
+/// Parses `input_file` with the [Parameters] set for it.
+fn parse_file<S: Solution>(input_file: &Path) -> Result<S::Input, AocError> {
+    S::parse_with(&read_input(input_file)?, &get_parameters(input_file))
+}
+
+pub fn solve_part1<S: Solution>(input_file: &Path) -> Result<S::Answer1, AocError> {
+    let input = parse_file::<S>(input_file)?;
+    Ok(S::part1(&input))
+}
+
+pub fn solve_part2<S: Solution>(input_file: &Path) -> Result<S::Answer2, AocError> {
+    let input = parse_file::<S>(input_file)?;
+    Ok(S::part2(&input))
+}
+
+/// Solves both parts and prints their answers. Exits the process if the input cannot be read or
+/// parsed.
+pub fn run<S: Solution>(input_file: &Path) {
+    let input = match parse_file::<S>(input_file) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not load {}: {e}", input_file.display());
+            process::exit(1);
+        }
+    };
+    println!("Result of part 1 is {}", S::part1(&input));
+    println!("Result of part 2 is {}", S::part2(&input));
+}
+
+/// One of the two parts of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+/// Answer of a single part rendered to a string, with the time it took to solve it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Outcome of solving a day through [solve_timed]. A part that was not requested is `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayReport {
+    pub parse_elapsed: Duration,
+    pub part1: Option<PartReport>,
+    pub part2: Option<PartReport>,
+}
+
+/// Type erased entry point of a day, so days with different answer types can live in one table.
+pub type DayFn = fn(&str, &Parameters, &[Part]) -> Result<DayReport, AocError>;
+
+/// Parses `input` with `parameters` and solves the requested `parts`, timing each phase. Without
+/// any parts the input is only parsed. Coerces to [DayFn].
+pub fn solve_timed<S: Solution>(
+    input: &str,
+    parameters: &Parameters,
+    parts: &[Part],
+) -> Result<DayReport, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, parameters)?;
+    let parse_elapsed = start.elapsed();
+
+    let mut report = DayReport {
+        parse_elapsed,
+        ..Default::default()
+    };
+
+    if parts.contains(&Part::One) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed).to_string();
+        report.part1 = Some(PartReport {
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    if parts.contains(&Part::Two) {
+        let start = Instant::now();
+        let answer = S::part2(&parsed).to_string();
+        report.part2 = Some(PartReport {
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(report)
+}
+
+/// A named implementation of a day. Every day lists its implementations in `variants()`, the
+/// first one being the main implementation.
+pub type Variant = (&'static str, DayFn);
+
+/// Solves `input_file` with every variant and asserts that they all agree with the first one.
+/// A part that a variant answers with [NoAnswer], or an empty [MaybeAnswer], is not compared.
+pub fn assert_variants_agree(variants: &[Variant], input_file: &Path) {
+    let input = read_input(input_file).unwrap_or_else(|e| fail(input_file, e));
+    let parameters = get_parameters(input_file);
+    let no_answer = NoAnswer.to_string();
+
+    let (main_name, main_fn) = variants[0];
+    let expected =
+        main_fn(&input, &parameters, &Part::BOTH).unwrap_or_else(|e| fail(input_file, e));
+
+    for (name, variant_fn) in &variants[1..] {
+        let report =
+            variant_fn(&input, &parameters, &Part::BOTH).unwrap_or_else(|e| fail(input_file, e));
+        for (part, expected, actual) in [
+            (1, &expected.part1, &report.part1),
+            (2, &expected.part2, &report.part2),
+        ] {
+            let expected = &expected.as_ref().unwrap().answer;
+            let actual = &actual.as_ref().unwrap().answer;
+            if *actual == no_answer || *expected == no_answer {
+                continue;
+            }
+            assert_eq!(
+                actual,
+                expected,
+                "Variant {name} disagrees with {main_name} on part {part} of {}",
+                input_file.display()
+            );
+        }
+    }
+}
+
+/// Solves part 1 for `input_file` and asserts the answer against the expected one from the
+/// answers file next to it.
+pub fn assert_part1<S: Solution>(input_file: &Path) {
+    let expected = expect_answer(input_file, 1);
+    let answer = solve_part1::<S>(input_file)
+        .unwrap_or_else(|e| fail(input_file, e))
+        .to_string();
+    assert_eq!(
+        answer,
+        expected,
+        "Wrong answer for part 1 of {}",
+        input_file.display()
+    );
+}
+
+/// Solves part 2 for `input_file` and asserts the answer against the expected one from the
+/// answers file next to it.
+pub fn assert_part2<S: Solution>(input_file: &Path) {
+    let expected = expect_answer(input_file, 2);
+    let answer = solve_part2::<S>(input_file)
+        .unwrap_or_else(|e| fail(input_file, e))
+        .to_string();
+    assert_eq!(
+        answer,
+        expected,
+        "Wrong answer for part 2 of {}",
+        input_file.display()
+    );
+}
+
+fn expect_answer(input_file: &Path, part: u8) -> String {
+    get_expected_answer(input_file, part).unwrap_or_else(|| {
+        panic!(
+            "No expected answer for part {part} of {}",
+            input_file.display()
+        )
+    })
+}
+
+fn fail(input_file: &Path, e: AocError) -> ! {
+    panic!("Could not load {}: {e}", input_file.display())
+}
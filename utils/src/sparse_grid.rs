@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use crate::grid::{Adjacency, Grid};
+use crate::point::MapPoint;
+
+/// Width and height of the square chunks a [SparseGrid] stores its cells in.
+pub const CHUNK_SIZE: i64 = 16;
+
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    cells: Vec<Option<T>>,
+    count: usize,
+}
+
+impl<T> Chunk<T> {
+    fn new() -> Self {
+        Self {
+            cells: (0..CHUNK_SIZE * CHUNK_SIZE).map(|_| None).collect(),
+            count: 0,
+        }
+    }
+}
+
+/// A grid without fixed bounds where only the cells that have a value take up memory, for
+/// worlds that grow in every direction or are mostly empty.
+///
+/// Cells are kept in chunks of [CHUNK_SIZE] x [CHUNK_SIZE], so clustered cells stay close
+/// together. The bounding box of all cells is kept up to date on every change.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    chunks: HashMap<MapPoint, Chunk<T>>,
+    len: usize,
+    bounds: Option<(MapPoint, MapPoint)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            chunks: HashMap::new(),
+            len: 0,
+            bounds: None,
+        }
+    }
+
+    /// The chunk of `point` and the index of the cell inside it.
+    fn locate(point: &MapPoint) -> (MapPoint, usize) {
+        let chunk = MapPoint::new(
+            point.x.div_euclid(CHUNK_SIZE),
+            point.y.div_euclid(CHUNK_SIZE),
+        );
+        let index = point.y.rem_euclid(CHUNK_SIZE) * CHUNK_SIZE + point.x.rem_euclid(CHUNK_SIZE);
+        (chunk, index as usize)
+    }
+
+    fn point_of(chunk: &MapPoint, index: usize) -> MapPoint {
+        let index = index as i64;
+        MapPoint::new(
+            chunk.x * CHUNK_SIZE + index % CHUNK_SIZE,
+            chunk.y * CHUNK_SIZE + index / CHUNK_SIZE,
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Top left and bottom right corner of the smallest box around all cells, both included.
+    /// `None` for an empty grid.
+    pub fn bounds(&self) -> Option<(MapPoint, MapPoint)> {
+        self.bounds
+    }
+
+    pub fn contains(&self, point: &MapPoint) -> bool {
+        self.get(point).is_some()
+    }
+
+    pub fn get(&self, point: &MapPoint) -> Option<&T> {
+        let (chunk, index) = Self::locate(point);
+        self.chunks.get(&chunk)?.cells[index].as_ref()
+    }
+
+    pub fn get_mut(&mut self, point: &MapPoint) -> Option<&mut T> {
+        let (chunk, index) = Self::locate(point);
+        self.chunks.get_mut(&chunk)?.cells[index].as_mut()
+    }
+
+    /// Sets the cell at `point`, returns the value it had before.
+    pub fn insert(&mut self, point: MapPoint, value: T) -> Option<T> {
+        let (chunk, index) = Self::locate(&point);
+        let chunk = self.chunks.entry(chunk).or_insert_with(Chunk::new);
+        let previous = chunk.cells[index].replace(value);
+        if previous.is_none() {
+            chunk.count += 1;
+            self.len += 1;
+            self.bounds = Some(match self.bounds {
+                None => (point, point),
+                Some((min, max)) => (
+                    MapPoint::new(min.x.min(point.x), min.y.min(point.y)),
+                    MapPoint::new(max.x.max(point.x), max.y.max(point.y)),
+                ),
+            });
+        }
+        previous
+    }
+
+    /// The value at `point`, which is set to `default()` first if the cell is empty.
+    pub fn get_or_insert_with(&mut self, point: MapPoint, default: impl FnOnce() -> T) -> &mut T {
+        if !self.contains(&point) {
+            self.insert(point, default());
+        }
+        self.get_mut(&point).unwrap()
+    }
+
+    /// Empties the cell at `point`, returns the value it had.
+    pub fn remove(&mut self, point: &MapPoint) -> Option<T> {
+        let (chunk_position, index) = Self::locate(point);
+        let chunk = self.chunks.get_mut(&chunk_position)?;
+        let removed = chunk.cells[index].take()?;
+        chunk.count -= 1;
+        if chunk.count == 0 {
+            self.chunks.remove(&chunk_position);
+        }
+        self.len -= 1;
+
+        // Only a cell on the border can make the box smaller.
+        if let Some((min, max)) = self.bounds {
+            if point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y {
+                self.recompute_bounds();
+            }
+        }
+        Some(removed)
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self.iter().fold(None, |bounds, (point, _)| {
+            Some(match bounds {
+                None => (point, point),
+                Some((min, max)) => (
+                    MapPoint::new(min.x.min(point.x), min.y.min(point.y)),
+                    MapPoint::new(max.x.max(point.x), max.y.max(point.y)),
+                ),
+            })
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+        self.bounds = None;
+    }
+
+    /// All cells with their position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (MapPoint, &T)> {
+        self.chunks.iter().flat_map(|(chunk, cells)| {
+            cells
+                .cells
+                .iter()
+                .enumerate()
+                .filter_map(move |(index, cell)| {
+                    cell.as_ref()
+                        .map(|value| (Self::point_of(chunk, index), value))
+                })
+        })
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = MapPoint> + '_ {
+        self.iter().map(|(point, _)| point)
+    }
+
+    /// The filled cells next to `point`.
+    pub fn neighbors(
+        &self,
+        point: &MapPoint,
+        adjacency: Adjacency,
+    ) -> impl Iterator<Item = (MapPoint, &T)> {
+        let neighbors: Vec<MapPoint> = match adjacency {
+            Adjacency::FourWay => point.neighbors().collect(),
+            Adjacency::EightWay => point.neighbors_with_diagonals().collect(),
+        };
+        neighbors
+            .into_iter()
+            .filter_map(|neighbor| self.get(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// Takes every cell of `grid` that `keep` holds for, `(0, 0)` stays `(0, 0)`.
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.iter_points()
+            .filter(|(_, value)| keep(value))
+            .map(|(point, value)| (MapPoint::new(point.x as i64, point.y as i64), value.clone()))
+            .collect()
+    }
+
+    /// A dense copy of [SparseGrid::bounds], empty cells become `fill`. The top left cell of the
+    /// result is the top left corner of the bounds.
+    pub fn to_grid(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return Grid::with_width_height(0, 0, fill);
+        };
+        self.to_grid_area(min, max, fill)
+    }
+
+    /// A dense copy of the box from `min` to `max`, both included.
+    pub fn to_grid_area(&self, min: MapPoint, max: MapPoint, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        let mut rtn = Grid::with_width_height(width, height, fill);
+        for (point, value) in self.iter() {
+            if let Some(position) = (point - min).try_cast::<usize>() {
+                if let Some(cell) = rtn.get_mut(position.x, position.y) {
+                    *cell = value.clone();
+                }
+            }
+        }
+        rtn
+    }
+
+    /// Draws [SparseGrid::bounds] with one char per cell, `cell` gets `None` for empty cells.
+    pub fn render(&self, cell: impl FnMut(Option<&T>) -> char) -> String {
+        match self.bounds {
+            None => String::new(),
+            Some((min, max)) => self.render_area(min, max, cell),
+        }
+    }
+
+    /// Draws the box from `min` to `max`, both included, one line per row.
+    pub fn render_area(
+        &self,
+        min: MapPoint,
+        max: MapPoint,
+        mut cell: impl FnMut(Option<&T>) -> char,
+    ) -> String {
+        let mut rtn = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                rtn.push(cell(self.get(&MapPoint::new(x, y))));
+            }
+            rtn.push('\n');
+        }
+        rtn
+    }
+}
+
+impl<T> FromIterator<(MapPoint, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (MapPoint, T)>>(iter: I) -> Self {
+        let mut rtn = SparseGrid::new();
+        rtn.extend(iter);
+        rtn
+    }
+}
+
+impl<T> Extend<(MapPoint, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (MapPoint, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod sparse_grid_test {
+    use crate::grid::{Adjacency, Grid};
+    use crate::point::MapPoint;
+    use crate::sparse_grid::{SparseGrid, CHUNK_SIZE};
+
+    #[test]
+    fn test_insert_remove_and_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(MapPoint::new(-3, 2), 'a');
+        grid.insert(MapPoint::new(5 * CHUNK_SIZE, -1), 'b');
+        grid.insert(MapPoint::new(0, 0), 'c');
+        assert_eq!(grid.insert(MapPoint::new(0, 0), 'd'), Some('c'));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(
+            grid.bounds(),
+            Some((MapPoint::new(-3, -1), MapPoint::new(5 * CHUNK_SIZE, 2)))
+        );
+
+        assert_eq!(grid.remove(&MapPoint::new(5 * CHUNK_SIZE, -1)), Some('b'));
+        assert_eq!(grid.remove(&MapPoint::new(5 * CHUNK_SIZE, -1)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((MapPoint::new(-3, 0), MapPoint::new(0, 2)))
+        );
+        assert_eq!(grid.get(&MapPoint::new(-3, 2)), Some(&'a'));
+        assert_eq!(grid.get(&MapPoint::new(-2, 2)), None);
+    }
+
+    #[test]
+    fn test_dense_round_trip_and_render() {
+        let dense: Grid<char> = "#..\n.#.\n..#\n".parse().unwrap();
+        let mut sparse = SparseGrid::from_grid(&dense, |&c| c == '#');
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(sparse.to_grid('.'), dense);
+
+        sparse.insert(MapPoint::new(-1, 0), '#');
+        assert_eq!(
+            sparse.render(|cell| cell.copied().unwrap_or('.')),
+            "##..\n..#.\n...#\n"
+        );
+
+        let neighbors: Vec<MapPoint> = sparse
+            .neighbors(&MapPoint::new(1, 1), Adjacency::EightWay)
+            .map(|(point, _)| point)
+            .collect();
+        assert_eq!(neighbors, [MapPoint::new(0, 0), MapPoint::new(2, 2)]);
+        assert_eq!(
+            sparse
+                .neighbors(&MapPoint::new(1, 1), Adjacency::FourWay)
+                .count(),
+            0
+        );
+    }
+}
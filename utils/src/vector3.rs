@@ -1,4 +1,10 @@
-#[derive(Debug, Clone, Copy, Default, Hash)]
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or an offset in space.
+///
+/// The rotations follow the right hand rule: with the thumb along the positive axis the fingers
+/// point in the direction of the turn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Vector3 {
     pub x: i64,
     pub y: i64,
@@ -6,16 +12,251 @@ pub struct Vector3 {
 }
 
 impl Vector3 {
-    // fn manhatten_distance(&self, other: &Vector3) -> i64 {
-    //     manhatten_distance_3d(self.x, other.x, self.y, other.y, self.z, other.z)
-    // }
-
-    // fn manhatten_distance_to(&self, x: i64, y: i64, z: i64) -> i64 {
-    //     manhatten_distance_3d(self.x, x, self.y, y, self.z, z)
-    // }
-    pub fn add(&mut self, other: &Self) {
-        self.x += other.x;
-        self.y += other.y;
-        self.z += other.z;
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> i64 {
+        (self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)) as i64
+    }
+
+    /// Number of king moves between both points, diagonal steps included.
+    pub fn chebyshev_distance(&self, other: &Self) -> i64 {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z)) as i64
+    }
+
+    /// Squared length of the straight line between both points, exact unlike
+    /// [Vector3::euclidean_distance].
+    pub fn squared_distance(&self, other: &Self) -> i64 {
+        let diff = *self - *other;
+        diff.x * diff.x + diff.y * diff.y + diff.z * diff.z
+    }
+
+    pub fn euclidean_distance(&self, other: &Self) -> f64 {
+        (self.squared_distance(other) as f64).sqrt()
+    }
+
+    /// The smaller value of every coordinate.
+    pub fn component_min(&self, other: &Self) -> Self {
+        Vector3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// The larger value of every coordinate.
+    pub fn component_max(&self, other: &Self) -> Self {
+        Vector3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// The six points that share a face with this one, along x, then y, then z.
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        [
+            Vector3::new(-1, 0, 0),
+            Vector3::new(1, 0, 0),
+            Vector3::new(0, -1, 0),
+            Vector3::new(0, 1, 0),
+            Vector3::new(0, 0, -1),
+            Vector3::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |offset| point + offset)
+    }
+
+    /// All 26 points of the surrounding 3x3x3 cube.
+    pub fn neighbors_with_diagonals(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| Vector3::new(x, y, z))))
+            .filter(|offset| *offset != Vector3::ZERO)
+            .map(move |offset| point + offset)
+    }
+
+    /// Turns the vector by 90° around the x axis, y becomes z.
+    pub fn rotate_x(self) -> Self {
+        Vector3::new(self.x, -self.z, self.y)
+    }
+
+    /// Turns the vector by 90° around the y axis, z becomes x.
+    pub fn rotate_y(self) -> Self {
+        Vector3::new(self.z, self.y, -self.x)
+    }
+
+    /// Turns the vector by 90° around the z axis, x becomes y.
+    pub fn rotate_z(self) -> Self {
+        Vector3::new(-self.y, self.x, self.z)
+    }
+
+    /// The vector in all 24 orientations a cube can be turned into, starting with the vector
+    /// itself. The order is the same for every vector, so the n-th entries of two vectors belong
+    /// to the same rotation.
+    pub fn orientations(self) -> [Self; 24] {
+        let mut rtn = [self; 24];
+        for roll in 0..4 {
+            let mut rolled = self;
+            for _ in 0..roll {
+                rolled = rolled.rotate_x();
+            }
+            // Points the former x axis to each of the six sides.
+            let faces = [
+                rolled,
+                rolled.rotate_z(),
+                rolled.rotate_z().rotate_z(),
+                rolled.rotate_z().rotate_z().rotate_z(),
+                rolled.rotate_y(),
+                rolled.rotate_y().rotate_y().rotate_y(),
+            ];
+            for (face, vector) in faces.into_iter().enumerate() {
+                rtn[face * 4 + roll] = vector;
+            }
+        }
+        rtn
+    }
+}
+
+/// Smallest and largest corner of the box around all `points`, both included. `None` without
+/// points.
+pub fn bounding_box(points: impl IntoIterator<Item = Vector3>) -> Option<(Vector3, Vector3)> {
+    points.into_iter().fold(None, |bounds, point| {
+        Some(match bounds {
+            None => (point, point),
+            Some((min, max)) => (min.component_min(&point), max.component_max(&point)),
+        })
+    })
+}
+
+impl Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Scales all coordinates.
+impl Mul<i64> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Vector3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl From<(i64, i64, i64)> for Vector3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod vector3_test {
+    use std::collections::HashSet;
+
+    use crate::vector3::{bounding_box, Vector3};
+
+    #[test]
+    fn test_operators_and_distances() {
+        let a = Vector3::new(1, -2, 3);
+        let b = Vector3::new(4, 2, 3);
+        assert_eq!(a + b, Vector3::new(5, 0, 6));
+        assert_eq!(b - a, Vector3::new(3, 4, 0));
+        assert_eq!(a * -2, Vector3::new(-2, 4, -6));
+        assert_eq!(-a, Vector3::new(-1, 2, -3));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_distance(&b), 25);
+        assert_eq!(a.euclidean_distance(&b), 5.0);
+    }
+
+    #[test]
+    fn test_neighbors_and_bounds() {
+        let origin = Vector3::ZERO;
+        assert_eq!(origin.neighbors().count(), 6);
+        assert!(origin
+            .neighbors()
+            .all(|n| n.manhattan_distance(&origin) == 1));
+        let all: HashSet<Vector3> = origin.neighbors_with_diagonals().collect();
+        assert_eq!(all.len(), 26);
+        assert!(!all.contains(&origin));
+
+        assert_eq!(bounding_box([]), None);
+        assert_eq!(
+            bounding_box([Vector3::new(1, -5, 2), Vector3::new(-3, 0, 7)]),
+            Some((Vector3::new(-3, -5, 2), Vector3::new(1, 0, 7)))
+        );
+    }
+
+    #[test]
+    fn test_rotations() {
+        let x = Vector3::new(1, 0, 0);
+        let y = Vector3::new(0, 1, 0);
+        let z = Vector3::new(0, 0, 1);
+        assert_eq!(y.rotate_x(), z);
+        assert_eq!(z.rotate_y(), x);
+        assert_eq!(x.rotate_z(), y);
+
+        let v = Vector3::new(1, 2, 3);
+        assert_eq!(v.rotate_x().rotate_x().rotate_x().rotate_x(), v);
+        assert_eq!(v.rotate_y().rotate_y(), Vector3::new(-1, 2, -3));
+
+        let orientations = v.orientations();
+        assert_eq!(orientations[0], v);
+        assert_eq!(orientations.iter().collect::<HashSet<_>>().len(), 24);
+        // Every orientation keeps the handedness, so the cross product of x and y stays z.
+        let (xs, ys, zs) = (x.orientations(), y.orientations(), z.orientations());
+        for i in 0..24 {
+            let (a, b) = (xs[i], ys[i]);
+            let cross = Vector3::new(
+                a.y * b.z - a.z * b.y,
+                a.z * b.x - a.x * b.z,
+                a.x * b.y - a.y * b.x,
+            );
+            assert_eq!(cross, zs[i]);
+        }
     }
 }